@@ -0,0 +1,161 @@
+use crate::multilinear_polynomial::eq::eq_poly;
+use crate::multilinear_polynomial::multilinear::MultilinearPoly;
+use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+use ark_ff::{FftField, Field};
+
+// ============= DENSE =============
+
+impl<F: FftField> UnivariatePolyDense<F> {
+    /// Computes `sum_i coeffs[i] * polys[i]` by accumulating into a single coefficient vector.
+    pub fn linear_combination(polys: &[Self], coeffs: &[F]) -> Self {
+        assert_eq!(
+            polys.len(),
+            coeffs.len(),
+            "Expected one coefficient per polynomial"
+        );
+
+        let len = polys
+            .iter()
+            .map(|poly| poly.coefficient.len())
+            .max()
+            .unwrap_or(0);
+        let mut result = vec![F::zero(); len];
+        for (poly, scalar) in polys.iter().zip(coeffs) {
+            for (acc, coeff) in result.iter_mut().zip(&poly.coefficient) {
+                *acc += *coeff * scalar;
+            }
+        }
+        UnivariatePolyDense::new(result)
+    }
+
+    /// Evaluates every polynomial at the same point.
+    pub fn batch_evaluate(polys: &[Self], point: F) -> Vec<F> {
+        polys.iter().map(|poly| poly.evaluate(point)).collect()
+    }
+}
+
+// ============= MULTILINEAR =============
+
+impl<F: Field> MultilinearPoly<F> {
+    /// Computes `sum_i coeffs[i] * polys[i]`, where every polynomial has `num_vars` variables.
+    /// The number of variables is explicit so that, as for dense univariates, an empty list
+    /// gives the zero polynomial.
+    pub fn linear_combination(num_vars: usize, polys: &[Self], coeffs: &[F]) -> Self {
+        assert_eq!(
+            polys.len(),
+            coeffs.len(),
+            "Expected one coefficient per polynomial"
+        );
+        assert!(
+            polys.iter().all(|poly| poly.num_vars == num_vars),
+            "All polynomials must have the same number of variables"
+        );
+
+        let mut evaluations = vec![F::zero(); 1 << num_vars];
+        for (poly, scalar) in polys.iter().zip(coeffs) {
+            for (acc, eval) in evaluations.iter_mut().zip(&poly.evaluations) {
+                *acc += *eval * scalar;
+            }
+        }
+        MultilinearPoly {
+            num_vars,
+            evaluations,
+        }
+    }
+
+    /// Evaluates every polynomial at the same point. The Lagrange basis at `point` is built
+    /// once, after which each evaluation is a single inner product with the table.
+    pub fn batch_evaluate(polys: &[Self], point: &[F]) -> Vec<F> {
+        assert!(
+            polys.iter().all(|poly| poly.num_vars == point.len()),
+            "Number of assignments must equal the number of variables"
+        );
+
+        let basis = eq_poly(point).evaluations;
+        polys
+            .iter()
+            .map(|poly| {
+                poly.evaluations
+                    .iter()
+                    .zip(&basis)
+                    .map(|(eval, weight)| *eval * weight)
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_linear_combination_dense() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let polys: Vec<UnivariatePolyDense<Fr>> = [3, 7, 0]
+            .iter()
+            .map(|degree| UnivariatePolyDense::rand(*degree, &mut rng))
+            .collect();
+        let coeffs = [Fr::from(2), Fr::from(5), Fr::from(11)];
+
+        let expected: UnivariatePolyDense<Fr> =
+            polys.iter().zip(coeffs).map(|(poly, c)| poly * c).sum();
+        assert_eq!(UnivariatePolyDense::linear_combination(&polys, &coeffs), expected);
+
+        // Cancelling leading terms still gives a canonical result.
+        let cancelled = UnivariatePolyDense::linear_combination(
+            &[polys[1].clone(), polys[1].clone()],
+            &[Fr::from(1), -Fr::from(1)],
+        );
+        assert_eq!(cancelled, UnivariatePolyDense::new(vec![]));
+    }
+
+    #[test]
+    fn test_batch_evaluate_dense() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let polys: Vec<UnivariatePolyDense<Fr>> =
+            (0..4).map(|degree| UnivariatePolyDense::rand(degree, &mut rng)).collect();
+        let point = Fr::from(9);
+
+        let expected: Vec<Fr> = polys.iter().map(|poly| poly.evaluate(point)).collect();
+        assert_eq!(UnivariatePolyDense::batch_evaluate(&polys, point), expected);
+    }
+
+    #[test]
+    fn test_linear_combination_and_batch_evaluate_multilinear() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let polys: Vec<MultilinearPoly<Fr>> =
+            (0..3).map(|_| MultilinearPoly::rand(4, &mut rng)).collect();
+        let coeffs = [Fr::from(3), Fr::from(1), -Fr::from(4)];
+        let point = vec![Fr::from(2), Fr::from(7), Fr::from(0), Fr::from(13)];
+
+        let evaluations = MultilinearPoly::batch_evaluate(&polys, &point);
+        for (poly, evaluation) in polys.iter().zip(&evaluations) {
+            assert_eq!(poly.evaluate(point.clone()), *evaluation);
+        }
+
+        let combined = MultilinearPoly::linear_combination(4, &polys, &coeffs);
+        let expected: Fr = coeffs.iter().zip(&evaluations).map(|(c, e)| *c * e).sum();
+        assert_eq!(combined.evaluate(point), expected);
+    }
+
+    #[test]
+    fn test_linear_combination_of_nothing_is_zero() {
+        assert_eq!(
+            UnivariatePolyDense::<Fr>::linear_combination(&[], &[]),
+            UnivariatePolyDense::new(vec![])
+        );
+        let empty = MultilinearPoly::<Fr>::linear_combination(3, &[], &[]);
+        assert_eq!(empty.num_vars, 3);
+        assert_eq!(empty.evaluations, vec![Fr::from(0); 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "All polynomials must have the same number of variables")]
+    fn test_linear_combination_multilinear_wrong_num_vars() {
+        let poly = MultilinearPoly::new(1, vec![Fr::from(1), Fr::from(2)]);
+        MultilinearPoly::linear_combination(2, &[poly], &[Fr::from(1)]);
+    }
+}
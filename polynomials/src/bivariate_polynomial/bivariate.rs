@@ -0,0 +1,243 @@
+use crate::univariate_polynomial::multipoint::SubproductTree;
+use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+use ark_ff::{FftField, Zero};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// ============= STRUCTS =============
+/// Dense coefficient matrix: `coefficients[i][j]` is the coefficient of `X^i Y^j`. Every row has
+/// the same length, and the matrix is always canonical: no trailing zero rows or columns, so
+/// the zero polynomial has no rows at all. The field is private so that this holds; read it
+/// through [`BivariatePoly::coefficients`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BivariatePoly<F: FftField> {
+    pub(crate) coefficients: Vec<Vec<F>>,
+}
+
+// ============= IMPLEMENTATIONS =============
+
+impl<F: FftField> BivariatePoly<F> {
+    pub fn new(mut coefficients: Vec<Vec<F>>) -> Self {
+        let num_cols = coefficients.first().map_or(0, |row| row.len());
+        assert!(
+            coefficients.iter().all(|row| row.len() == num_cols),
+            "Every row of the coefficient matrix must have the same length"
+        );
+
+        while coefficients
+            .last()
+            .is_some_and(|row| row.iter().all(|coeff| coeff.is_zero()))
+        {
+            coefficients.pop();
+        }
+
+        let num_cols = (0..num_cols)
+            .rev()
+            .find(|&j| coefficients.iter().any(|row| !row[j].is_zero()))
+            .map_or(0, |j| j + 1);
+        coefficients
+            .iter_mut()
+            .for_each(|row| row.truncate(num_cols));
+
+        BivariatePoly { coefficients }
+    }
+
+    /// The canonical coefficient matrix, indexed as `[power of X][power of Y]`.
+    pub fn coefficients(&self) -> &[Vec<F>] {
+        &self.coefficients
+    }
+
+    /// Embeds a univariate polynomial in `X`.
+    pub fn from_x(poly: &UnivariatePolyDense<F>) -> Self {
+        Self::new(poly.coefficient.iter().map(|coeff| vec![*coeff]).collect())
+    }
+
+    /// Embeds a univariate polynomial in `Y`.
+    pub fn from_y(poly: &UnivariatePolyDense<F>) -> Self {
+        Self::new(vec![poly.coefficient.clone()])
+    }
+
+    fn num_rows(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    fn num_cols(&self) -> usize {
+        self.coefficients.first().map_or(0, |row| row.len())
+    }
+
+    /// Degree in `X`, with the zero polynomial having degree 0.
+    pub fn degree_x(&self) -> usize {
+        self.num_rows().saturating_sub(1)
+    }
+
+    /// Degree in `Y`, with the zero polynomial having degree 0.
+    pub fn degree_y(&self) -> usize {
+        self.num_cols().saturating_sub(1)
+    }
+
+    pub fn evaluate(&self, x: F, y: F) -> F {
+        self.partial_evaluate_y(y).evaluate(x)
+    }
+
+    /// Fixes `X = x`, leaving a polynomial in `Y`.
+    pub fn partial_evaluate_x(&self, x: F) -> UnivariatePolyDense<F> {
+        let mut result = vec![F::zero(); self.num_cols()];
+        for row in self.coefficients.iter().rev() {
+            for (acc, coeff) in result.iter_mut().zip(row) {
+                *acc = *acc * x + coeff;
+            }
+        }
+        UnivariatePolyDense::new(result)
+    }
+
+    /// Fixes `Y = y`, leaving a polynomial in `X`.
+    pub fn partial_evaluate_y(&self, y: F) -> UnivariatePolyDense<F> {
+        UnivariatePolyDense::new(
+            self.coefficients
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .rev()
+                        .fold(F::zero(), |acc, coeff| acc * y + coeff)
+                })
+                .collect(),
+        )
+    }
+
+    pub fn scalar_mul(&self, scalar: &F) -> Self {
+        Self::new(
+            self.coefficients
+                .iter()
+                .map(|row| row.iter().map(|coeff| *coeff * scalar).collect())
+                .collect(),
+        )
+    }
+
+    /// Returns the polynomial of degree below `xs.len()` in `X` and below `ys.len()` in `Y` with
+    /// `f(xs[i], ys[j]) = values[i][j]`. The points along each axis must be distinct.
+    pub fn interpolate(xs: &[F], ys: &[F], values: &[Vec<F>]) -> Self {
+        assert_eq!(values.len(), xs.len(), "Expected one row of values per x");
+        assert!(
+            values.iter().all(|row| row.len() == ys.len()),
+            "Expected one value per y in every row"
+        );
+
+        // Interpolate each row along Y, then each resulting coefficient column along X.
+        let y_tree = SubproductTree::new(ys);
+        let rows: Vec<Vec<F>> = values
+            .iter()
+            .map(|row| {
+                let mut coefficient = y_tree.interpolate(row).coefficient;
+                coefficient.resize(ys.len(), F::zero());
+                coefficient
+            })
+            .collect();
+
+        let x_tree = SubproductTree::new(xs);
+        let mut coefficients = vec![vec![F::zero(); ys.len()]; xs.len()];
+        for j in 0..ys.len() {
+            let column: Vec<F> = rows.iter().map(|row| row[j]).collect();
+            for (i, coeff) in x_tree
+                .interpolate(&column)
+                .coefficient
+                .into_iter()
+                .enumerate()
+            {
+                coefficients[i][j] = coeff;
+            }
+        }
+
+        Self::new(coefficients)
+    }
+
+    // Kronecker substitution: X -> Y^stride packs each row into its own block of a univariate
+    // polynomial in Y, with `stride` wide enough that rows of a product never overlap.
+    fn pack(&self, stride: usize) -> UnivariatePolyDense<F> {
+        let mut packed = vec![F::zero(); self.num_rows() * stride];
+        for (block, row) in packed.chunks_mut(stride).zip(&self.coefficients) {
+            block[..row.len()].copy_from_slice(row);
+        }
+        UnivariatePolyDense::new(packed)
+    }
+}
+
+impl<F: FftField> Add for &BivariatePoly<F> {
+    type Output = BivariatePoly<F>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let num_rows = self.num_rows().max(rhs.num_rows());
+        let num_cols = self.num_cols().max(rhs.num_cols());
+
+        let mut coefficients = vec![vec![F::zero(); num_cols]; num_rows];
+        for poly in [self, rhs] {
+            for (sum_row, row) in coefficients.iter_mut().zip(&poly.coefficients) {
+                for (sum, coeff) in sum_row.iter_mut().zip(row) {
+                    *sum += coeff;
+                }
+            }
+        }
+        BivariatePoly::new(coefficients)
+    }
+}
+
+impl<F: FftField> Sub for &BivariatePoly<F> {
+    type Output = BivariatePoly<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl<F: FftField> Neg for &BivariatePoly<F> {
+    type Output = BivariatePoly<F>;
+
+    fn neg(self) -> Self::Output {
+        BivariatePoly {
+            coefficients: self
+                .coefficients
+                .iter()
+                .map(|row| row.iter().map(|coeff| -*coeff).collect())
+                .collect(),
+        }
+    }
+}
+
+impl<F: FftField> Neg for BivariatePoly<F> {
+    type Output = BivariatePoly<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<F: FftField> Mul for &BivariatePoly<F> {
+    type Output = BivariatePoly<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return BivariatePoly::zero();
+        }
+
+        let stride = self.num_cols() + rhs.num_cols() - 1;
+        let mut product = (&self.pack(stride) * &rhs.pack(stride)).coefficient;
+        product.resize((self.num_rows() + rhs.num_rows() - 1) * stride, F::zero());
+
+        BivariatePoly::new(product.chunks(stride).map(|row| row.to_vec()).collect())
+    }
+}
+
+forward_binop!(BivariatePoly, FftField, Add, add, AddAssign, add_assign);
+forward_binop!(BivariatePoly, FftField, Sub, sub, SubAssign, sub_assign);
+forward_binop!(BivariatePoly, FftField, Mul, mul, MulAssign, mul_assign);
+forward_scalar_mul!(BivariatePoly, FftField);
+
+impl<F: FftField> Zero for BivariatePoly<F> {
+    fn zero() -> Self {
+        BivariatePoly::new(vec![])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients
+            .iter()
+            .all(|row| row.iter().all(|coeff| coeff.is_zero()))
+    }
+}
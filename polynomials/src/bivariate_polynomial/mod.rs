@@ -0,0 +1,2 @@
+pub mod bivariate;
+mod test;
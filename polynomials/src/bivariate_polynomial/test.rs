@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod test {
+    use crate::bivariate_polynomial::bivariate::BivariatePoly;
+    use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+    use ark_bn254::Fr;
+    use ark_ff::{Field, Zero};
+
+    fn fr_matrix(rows: &[&[u64]]) -> Vec<Vec<Fr>> {
+        rows.iter()
+            .map(|row| row.iter().map(|c| Fr::from(*c)).collect())
+            .collect()
+    }
+
+    // 1 + 2y + 3x + 4xy + 5x^2
+    fn poly_1() -> BivariatePoly<Fr> {
+        BivariatePoly::new(fr_matrix(&[&[1, 2], &[3, 4], &[5, 0]]))
+    }
+
+    // 2 + x y^2
+    fn poly_2() -> BivariatePoly<Fr> {
+        BivariatePoly::new(fr_matrix(&[&[2, 0, 0], &[0, 0, 1]]))
+    }
+
+    fn evaluate_termwise(poly: &BivariatePoly<Fr>, x: Fr, y: Fr) -> Fr {
+        let mut sum = Fr::zero();
+        for (i, row) in poly.coefficients.iter().enumerate() {
+            for (j, coeff) in row.iter().enumerate() {
+                sum += *coeff * x.pow([i as u64]) * y.pow([j as u64]);
+            }
+        }
+        sum
+    }
+
+    #[test]
+    fn test_new_is_canonical() {
+        let padded = BivariatePoly::new(fr_matrix(&[&[1, 2, 0], &[3, 0, 0], &[0, 0, 0]]));
+        assert_eq!(padded.coefficients(), fr_matrix(&[&[1, 2], &[3, 0]]));
+        assert_eq!((padded.degree_x(), padded.degree_y()), (1, 1));
+        assert!(BivariatePoly::new(fr_matrix(&[&[0, 0]]))
+            .coefficients()
+            .is_empty());
+        assert_eq!((poly_1().degree_x(), poly_1().degree_y()), (2, 1));
+    }
+
+    #[test]
+    fn test_evaluate_and_partial_evaluate() {
+        let (x, y) = (Fr::from(3), Fr::from(7));
+        let poly = poly_1();
+        let expected = evaluate_termwise(&poly, x, y);
+
+        assert_eq!(poly.evaluate(x, y), expected);
+        assert_eq!(poly.partial_evaluate_x(x).evaluate(y), expected);
+        assert_eq!(poly.partial_evaluate_y(y).evaluate(x), expected);
+        assert_eq!(
+            poly.partial_evaluate_y(Fr::from(0)),
+            UnivariatePolyDense::new(vec![Fr::from(1), Fr::from(3), Fr::from(5)])
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (poly_1(), poly_2());
+        let (x, y) = (Fr::from(5), Fr::from(11));
+
+        assert_eq!(
+            (&a + &b).evaluate(x, y),
+            a.evaluate(x, y) + b.evaluate(x, y)
+        );
+        assert_eq!(
+            (&a - &b).evaluate(x, y),
+            a.evaluate(x, y) - b.evaluate(x, y)
+        );
+        assert_eq!(
+            (&a * &b).evaluate(x, y),
+            a.evaluate(x, y) * b.evaluate(x, y)
+        );
+        assert_eq!(((&a * &b).degree_x(), (&a * &b).degree_y()), (3, 3));
+        assert_eq!((&a * Fr::from(2)), &a + &a);
+        assert!((&a - &a).is_zero());
+        assert!((&a * &BivariatePoly::zero()).is_zero());
+
+        let x_only =
+            BivariatePoly::from_x(&UnivariatePolyDense::new(vec![Fr::from(0), Fr::from(1)]));
+        let y_only =
+            BivariatePoly::from_y(&UnivariatePolyDense::new(vec![Fr::from(0), Fr::from(1)]));
+        assert_eq!(
+            x_only * y_only,
+            BivariatePoly::new(fr_matrix(&[&[0, 0], &[0, 1]]))
+        );
+    }
+
+    #[test]
+    fn test_interpolate_on_grid() {
+        let poly = &poly_1() * &poly_2();
+        let xs: Vec<Fr> = (1..=4).map(Fr::from).collect();
+        let ys: Vec<Fr> = (10..14).map(Fr::from).collect();
+        let values: Vec<Vec<Fr>> = xs
+            .iter()
+            .map(|x| ys.iter().map(|y| poly.evaluate(*x, *y)).collect())
+            .collect();
+
+        assert_eq!(BivariatePoly::interpolate(&xs, &ys, &values), poly);
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PolynomialError {
+    DivisionByZero,
+    NonZeroRemainder,
+    NotInvertible,
+    ParseError(String),
+    /// A multilinear evaluation table whose length is not `2^num_vars`.
+    InvalidEvaluationCount { num_vars: usize, len: usize },
+    /// A variable index at or beyond the polynomial's number of variables.
+    PositionOutOfRange { position: usize, num_vars: usize },
+    /// A point whose number of coordinates differs from the number of variables.
+    WrongNumberOfAssignments { expected: usize, actual: usize },
+}
+
+impl fmt::Display for PolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolynomialError::DivisionByZero => write!(f, "Division by the zero polynomial"),
+            PolynomialError::NonZeroRemainder => {
+                write!(f, "Divisor does not divide the polynomial exactly")
+            }
+            PolynomialError::NotInvertible => {
+                write!(f, "Polynomial shares a factor with the modulus and has no inverse")
+            }
+            PolynomialError::ParseError(reason) => {
+                write!(f, "Could not parse polynomial: {}", reason)
+            }
+            PolynomialError::InvalidEvaluationCount { num_vars, len } => write!(
+                f,
+                "Not a valid Boolean hypercube evaluation! {} evaluations given for {} variables",
+                len, num_vars
+            ),
+            PolynomialError::PositionOutOfRange { position, num_vars } => write!(
+                f,
+                "Position {} is out of range for a polynomial with {} variables",
+                position, num_vars
+            ),
+            PolynomialError::WrongNumberOfAssignments { expected, actual } => write!(
+                f,
+                "Number of assignments must equal the number of variables: expected {}, got {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PolynomialError {}
@@ -0,0 +1,81 @@
+use crate::multilinear_polynomial::multilinear::MultilinearPoly;
+use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+use ark_ff::{FftField, Field, PrimeField};
+
+// Protocols over a small prime field draw their challenges from an extension `E` for soundness,
+// after which the polynomials they fold have to live in `E` as well. These lift a polynomial
+// over the base field into any extension of it, keeping it the same polynomial.
+
+impl<F: PrimeField> UnivariatePolyDense<F> {
+    pub fn to_extension<E: FftField<BasePrimeField = F>>(&self) -> UnivariatePolyDense<E> {
+        UnivariatePolyDense::new(
+            self.coefficient
+                .iter()
+                .map(|coeff| E::from_base_prime_field(*coeff))
+                .collect(),
+        )
+    }
+}
+
+impl<F: PrimeField> MultilinearPoly<F> {
+    pub fn to_extension<E: Field<BasePrimeField = F>>(&self) -> MultilinearPoly<E> {
+        MultilinearPoly {
+            num_vars: self.num_vars,
+            evaluations: self
+                .evaluations
+                .iter()
+                .map(|eval| E::from_base_prime_field(*eval))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fq2};
+
+    fn fq2(c0: u64, c1: u64) -> Fq2 {
+        Fq2::new(Fq::from(c0), Fq::from(c1))
+    }
+
+    #[test]
+    fn test_dense_over_extension_field() {
+        let base = UnivariatePolyDense::new(vec![Fq::from(1), Fq::from(2), Fq::from(3)]);
+        let lifted: UnivariatePolyDense<Fq2> = base.to_extension();
+        assert_eq!(
+            lifted.evaluate(Fq2::from(5)),
+            Fq2::from_base_prime_field(base.evaluate(Fq::from(5)))
+        );
+
+        // (x + i)(x - i) = x^2 + 1 once coefficients may leave the base field.
+        let plus_i = UnivariatePolyDense::new(vec![fq2(0, 1), Fq2::from(1)]);
+        let minus_i = UnivariatePolyDense::new(vec![-fq2(0, 1), Fq2::from(1)]);
+        let product = &plus_i * &minus_i;
+        assert_eq!(
+            product,
+            UnivariatePolyDense::new(vec![Fq2::from(1), Fq2::from(0), Fq2::from(1)])
+        );
+        assert_eq!(&product / &plus_i, minus_i);
+        assert_eq!(product.gcd(&plus_i), plus_i);
+    }
+
+    #[test]
+    fn test_multilinear_over_extension_field() {
+        let base =
+            MultilinearPoly::new(2, vec![Fq::from(0), Fq::from(2), Fq::from(3), Fq::from(5)]);
+        let lifted: MultilinearPoly<Fq2> = base.to_extension();
+        let point = vec![fq2(4, 7), fq2(1, 9)];
+
+        // The folded polynomial stays in the extension and agrees with a full evaluation.
+        let folded = lifted.partial_evaluate((0, point[0]));
+        assert_eq!(folded.evaluate(vec![point[1]]), lifted.evaluate(point.clone()));
+
+        let real_point = vec![Fq::from(4), Fq::from(1)];
+        let embedded = real_point.iter().map(|x| Fq2::from_base_prime_field(*x));
+        assert_eq!(
+            lifted.evaluate(embedded.collect()),
+            Fq2::from_base_prime_field(base.evaluate(real_point))
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use ark_ff::fields::{Fp2, Fp2Config, Fp4, Fp4Config, Fp64, MontBackend, MontConfig};
+use ark_ff::{AdditiveGroup, Field, MontFp};
+
+/// The BabyBear prime `p = 15 * 2^27 + 1`. Elements fit in 31 bits and the multiplicative
+/// group has two-adicity 27.
+#[derive(MontConfig)]
+#[modulus = "2013265921"]
+#[generator = "31"]
+pub struct BabyBearConfig;
+pub type BabyBear = Fp64<MontBackend<BabyBearConfig, 1>>;
+
+/// The quadratic extension `F_p[u] / (u^2 - 11)`, used as the middle of the tower for
+/// [`BabyBearExt4`].
+pub type BabyBearExt2 = Fp2<BabyBearExt2Config>;
+
+pub struct BabyBearExt2Config;
+
+impl Fp2Config for BabyBearExt2Config {
+    type Fp = BabyBear;
+
+    /// NONRESIDUE = 11
+    const NONRESIDUE: BabyBear = MontFp!("11");
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [BabyBear] = &[
+        // NONRESIDUE**(((p^0) - 1) / 2)
+        BabyBear::ONE,
+        // NONRESIDUE**(((p^1) - 1) / 2)
+        MontFp!("-1"),
+    ];
+}
+
+/// The quartic extension `F_p2[v] / (v^2 - u)`, i.e. `F_p[v] / (v^4 - 11)`, of about 124 bits.
+pub type BabyBearExt4 = Fp4<BabyBearExt4Config>;
+
+pub struct BabyBearExt4Config;
+
+impl Fp4Config for BabyBearExt4Config {
+    type Fp2Config = BabyBearExt2Config;
+
+    /// NONRESIDUE = u
+    const NONRESIDUE: BabyBearExt2 = BabyBearExt2::new(BabyBear::ZERO, BabyBear::ONE);
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP4_C1: &'static [BabyBear] = &[
+        // 11**(((p^0) - 1) / 4)
+        BabyBear::ONE,
+        // 11**(((p^1) - 1) / 4)
+        MontFp!("1728404513"),
+        // 11**(((p^2) - 1) / 4)
+        MontFp!("-1"),
+        // 11**(((p^3) - 1) / 4)
+        MontFp!("284861408"),
+    ];
+}
@@ -0,0 +1,157 @@
+use ark_ff::fields::{Fp, Fp2, Fp2Config, Fp64, FpConfig};
+use ark_ff::{BigInt, Field, SqrtPrecomputation};
+use ark_std::marker::PhantomData;
+
+/// The Goldilocks prime `p = 2^64 - 2^32 + 1`. Its multiplicative group has two-adicity 32,
+/// so domains of up to 2^32 points are available for NTTs, and elements fit in a single limb.
+///
+/// Elements are kept in canonical form and products are reduced with the special shape of `p`:
+/// `2^64 = 2^32 - 1` and `2^96 = -1`, so a 128-bit product folds back into 64 bits with a few
+/// additions instead of a Montgomery step.
+pub struct GoldilocksConfig;
+pub type Goldilocks = Fp64<GoldilocksConfig>;
+
+const MODULUS: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// `2^64 mod p`.
+const EPSILON: u64 = 0xFFFF_FFFF;
+
+/// Wraps a value that is already reduced mod `p`.
+const fn goldilocks(value: u64) -> Goldilocks {
+    Fp(BigInt([value]), PhantomData)
+}
+
+/// Reduces a 128-bit integer mod `p`.
+#[inline(always)]
+fn reduce128(x: u128) -> u64 {
+    let x_lo = x as u64;
+    let x_hi = (x >> 64) as u64;
+    let x_hi_hi = x_hi >> 32;
+    let x_hi_lo = x_hi & EPSILON;
+
+    // x_lo - x_hi_hi * 2^96 = x_lo + x_hi_hi * (-1).
+    let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+    if borrow {
+        // Wrapping added 2^64, which is EPSILON too much. This cannot underflow, since t0 is
+        // at least 2^64 - 2^32 after the wrap.
+        t0 = t0.wrapping_sub(EPSILON);
+    }
+    // x_hi_lo * 2^64 = x_hi_lo * EPSILON, which fits in 64 bits.
+    let t1 = x_hi_lo * EPSILON;
+    let (mut result, carry) = t0.overflowing_add(t1);
+    if carry {
+        // The lost 2^64 is worth EPSILON; the sum is small enough here for this not to wrap.
+        result = result.wrapping_add(EPSILON);
+    }
+    if result >= MODULUS {
+        result -= MODULUS;
+    }
+    result
+}
+
+impl FpConfig<1> for GoldilocksConfig {
+    const MODULUS: BigInt<1> = BigInt([MODULUS]);
+    const GENERATOR: Goldilocks = goldilocks(7);
+    const ZERO: Goldilocks = goldilocks(0);
+    const ONE: Goldilocks = goldilocks(1);
+    const TWO_ADICITY: u32 = 32;
+
+    /// GENERATOR^((p - 1) / 2^32)
+    const TWO_ADIC_ROOT_OF_UNITY: Goldilocks = goldilocks(1753635133440165772);
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Goldilocks>> =
+        Some(SqrtPrecomputation::TonelliShanks {
+            two_adicity: 32,
+            quadratic_nonresidue_to_trace: Self::TWO_ADIC_ROOT_OF_UNITY,
+            // ((p - 1) / 2^32 - 1) / 2
+            trace_of_modulus_minus_one_div_two: &[0x7FFF_FFFF],
+        });
+
+    #[inline(always)]
+    fn add_assign(a: &mut Goldilocks, b: &Goldilocks) {
+        let (mut sum, carry) = a.0 .0[0].overflowing_add(b.0 .0[0]);
+        if carry {
+            // Both inputs are below p, so adding back 2^64 mod p cannot wrap again.
+            sum += EPSILON;
+        }
+        if sum >= MODULUS {
+            sum -= MODULUS;
+        }
+        a.0 .0[0] = sum;
+    }
+
+    #[inline(always)]
+    fn sub_assign(a: &mut Goldilocks, b: &Goldilocks) {
+        let (mut difference, borrow) = a.0 .0[0].overflowing_sub(b.0 .0[0]);
+        if borrow {
+            // Wrapping added 2^64 rather than p.
+            difference -= EPSILON;
+        }
+        a.0 .0[0] = difference;
+    }
+
+    #[inline(always)]
+    fn double_in_place(a: &mut Goldilocks) {
+        let b = *a;
+        Self::add_assign(a, &b);
+    }
+
+    #[inline(always)]
+    fn neg_in_place(a: &mut Goldilocks) {
+        if a.0 .0[0] != 0 {
+            a.0 .0[0] = MODULUS - a.0 .0[0];
+        }
+    }
+
+    #[inline(always)]
+    fn mul_assign(a: &mut Goldilocks, b: &Goldilocks) {
+        a.0 .0[0] = reduce128(a.0 .0[0] as u128 * b.0 .0[0] as u128);
+    }
+
+    fn sum_of_products<const T: usize>(a: &[Goldilocks; T], b: &[Goldilocks; T]) -> Goldilocks {
+        a.iter().zip(b).fold(Self::ZERO, |mut sum, (a, b)| {
+            Self::add_assign(&mut sum, &(*a * b));
+            sum
+        })
+    }
+
+    #[inline(always)]
+    fn square_in_place(a: &mut Goldilocks) {
+        let b = *a;
+        Self::mul_assign(a, &b);
+    }
+
+    fn inverse(a: &Goldilocks) -> Option<Goldilocks> {
+        // Fermat: a^(p - 2) = a^(-1) for non-zero a.
+        (a.0 .0[0] != 0).then(|| a.pow([MODULUS - 2]))
+    }
+
+    fn from_bigint(other: BigInt<1>) -> Option<Goldilocks> {
+        (other.0[0] < MODULUS).then_some(goldilocks(other.0[0]))
+    }
+
+    fn into_bigint(other: Goldilocks) -> BigInt<1> {
+        other.0
+    }
+}
+
+/// The quadratic extension `F_p[u] / (u^2 - 7)`, giving a field of about 128 bits to draw
+/// challenges from.
+pub type GoldilocksExt2 = Fp2<GoldilocksExt2Config>;
+
+pub struct GoldilocksExt2Config;
+
+impl Fp2Config for GoldilocksExt2Config {
+    type Fp = Goldilocks;
+
+    /// NONRESIDUE = 7
+    const NONRESIDUE: Goldilocks = goldilocks(7);
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [Goldilocks] = &[
+        // NONRESIDUE**(((p^0) - 1) / 2)
+        Goldilocks::ONE,
+        // NONRESIDUE**(((p^1) - 1) / 2)
+        goldilocks(MODULUS - 1),
+    ];
+}
@@ -0,0 +1,161 @@
+use ark_ff::fields::{Fp, Fp2, Fp2Config, Fp64, FpConfig, QuadExtConfig, QuadExtField};
+use ark_ff::{AdditiveGroup, BigInt, Field, SqrtPrecomputation};
+use ark_std::marker::PhantomData;
+
+/// The Mersenne prime `p = 2^31 - 1`.
+///
+/// `p - 1` has two-adicity 1, so there are no radix-2 domains: polynomial products fall back to
+/// schoolbook multiplication, and protocols needing NTTs should use Goldilocks or BabyBear.
+///
+/// Elements are kept in canonical form. Since `2^31 = 1`, a 62-bit product reduces by adding its
+/// low 31 bits to the rest, and sums need a single conditional subtraction.
+pub struct Mersenne31Config;
+pub type Mersenne31 = Fp64<Mersenne31Config>;
+
+const MODULUS: u64 = (1 << 31) - 1;
+
+/// Wraps a value that is already reduced mod `p`.
+const fn mersenne31(value: u64) -> Mersenne31 {
+    Fp(BigInt([value]), PhantomData)
+}
+
+impl FpConfig<1> for Mersenne31Config {
+    const MODULUS: BigInt<1> = BigInt([MODULUS]);
+    const GENERATOR: Mersenne31 = mersenne31(7);
+    const ZERO: Mersenne31 = mersenne31(0);
+    const ONE: Mersenne31 = mersenne31(1);
+    const TWO_ADICITY: u32 = 1;
+
+    /// GENERATOR^((p - 1) / 2) = -1
+    const TWO_ADIC_ROOT_OF_UNITY: Mersenne31 = mersenne31(MODULUS - 1);
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Mersenne31>> =
+        Some(SqrtPrecomputation::Case3Mod4 {
+            // (p + 1) / 4
+            modulus_plus_one_div_four: &[1 << 29],
+        });
+
+    #[inline(always)]
+    fn add_assign(a: &mut Mersenne31, b: &Mersenne31) {
+        let sum = a.0 .0[0] + b.0 .0[0];
+        a.0 .0[0] = if sum >= MODULUS { sum - MODULUS } else { sum };
+    }
+
+    #[inline(always)]
+    fn sub_assign(a: &mut Mersenne31, b: &Mersenne31) {
+        let (a, b) = (&mut a.0 .0[0], b.0 .0[0]);
+        *a = if *a >= b { *a - b } else { *a + MODULUS - b };
+    }
+
+    #[inline(always)]
+    fn double_in_place(a: &mut Mersenne31) {
+        let b = *a;
+        Self::add_assign(a, &b);
+    }
+
+    #[inline(always)]
+    fn neg_in_place(a: &mut Mersenne31) {
+        if a.0 .0[0] != 0 {
+            a.0 .0[0] = MODULUS - a.0 .0[0];
+        }
+    }
+
+    #[inline(always)]
+    fn mul_assign(a: &mut Mersenne31, b: &Mersenne31) {
+        let product = a.0 .0[0] * b.0 .0[0];
+        // Both halves are below 2^31, and the sum never reaches 2p for a product of reduced
+        // inputs, so one subtraction is enough.
+        let folded = (product & MODULUS) + (product >> 31);
+        a.0 .0[0] = if folded >= MODULUS { folded - MODULUS } else { folded };
+    }
+
+    fn sum_of_products<const T: usize>(a: &[Mersenne31; T], b: &[Mersenne31; T]) -> Mersenne31 {
+        a.iter().zip(b).fold(Self::ZERO, |mut sum, (a, b)| {
+            Self::add_assign(&mut sum, &(*a * b));
+            sum
+        })
+    }
+
+    #[inline(always)]
+    fn square_in_place(a: &mut Mersenne31) {
+        let b = *a;
+        Self::mul_assign(a, &b);
+    }
+
+    fn inverse(a: &Mersenne31) -> Option<Mersenne31> {
+        // Fermat: a^(p - 2) = a^(-1) for non-zero a.
+        (a.0 .0[0] != 0).then(|| a.pow([MODULUS - 2]))
+    }
+
+    fn from_bigint(other: BigInt<1>) -> Option<Mersenne31> {
+        (other.0[0] < MODULUS).then_some(mersenne31(other.0[0]))
+    }
+
+    fn into_bigint(other: Mersenne31) -> BigInt<1> {
+        other.0
+    }
+}
+
+/// The complex extension `F_p[i] / (i^2 + 1)`, which exists because `p = 3 mod 4`.
+///
+/// At about 62 bits this is too small to draw sum-check or Fiat-Shamir challenges from; use
+/// [`Mersenne31Ext4`] for those.
+pub type Mersenne31Ext2 = Fp2<Mersenne31Ext2Config>;
+
+pub struct Mersenne31Ext2Config;
+
+impl Fp2Config for Mersenne31Ext2Config {
+    type Fp = Mersenne31;
+
+    /// NONRESIDUE = -1
+    const NONRESIDUE: Mersenne31 = mersenne31(MODULUS - 1);
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [Mersenne31] = &[
+        // NONRESIDUE**(((p^0) - 1) / 2)
+        Mersenne31::ONE,
+        // NONRESIDUE**(((p^1) - 1) / 2)
+        mersenne31(MODULUS - 1),
+    ];
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_in_place(fe: &mut Self::Fp) -> &mut Self::Fp {
+        fe.neg_in_place()
+    }
+}
+
+/// The quartic extension `F_p2[v] / (v^2 - (2 + i))`, of about 124 bits. `2 + i` is a
+/// non-square in `F_p2` because its norm 5 is a non-residue mod `p`.
+///
+/// arkworks' `Fp4` only supports a non-residue in `F_p`, so this tower implements
+/// `QuadExtConfig` directly with Frobenius coefficients in `F_p2`.
+pub type Mersenne31Ext4 = QuadExtField<Mersenne31Ext4Config>;
+
+pub struct Mersenne31Ext4Config;
+
+impl QuadExtConfig for Mersenne31Ext4Config {
+    type BasePrimeField = Mersenne31;
+    type BaseField = Mersenne31Ext2;
+    type FrobCoeff = Mersenne31Ext2;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 4;
+
+    /// NONRESIDUE = 2 + i
+    const NONRESIDUE: Mersenne31Ext2 = Mersenne31Ext2::new(mersenne31(2), Mersenne31::ONE);
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_C1: &'static [Mersenne31Ext2] = &[
+        // NONRESIDUE**(((p^0) - 1) / 2)
+        Mersenne31Ext2::new(Mersenne31::ONE, Mersenne31::ZERO),
+        // NONRESIDUE**(((p^1) - 1) / 2)
+        Mersenne31Ext2::new(mersenne31(21189756), mersenne31(42379512)),
+        // NONRESIDUE**(((p^2) - 1) / 2)
+        Mersenne31Ext2::new(mersenne31(MODULUS - 1), Mersenne31::ZERO),
+        // NONRESIDUE**(((p^3) - 1) / 2)
+        Mersenne31Ext2::new(mersenne31(2126293891), mersenne31(2105104135)),
+    ];
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Mersenne31Ext2, power: usize) {
+        *fe *= &Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD];
+    }
+}
@@ -0,0 +1,11 @@
+//! Small prime fields for 64- and 32-bit arithmetic, with extensions large enough to draw
+//! challenges from.
+//!
+//! Goldilocks and Mersenne-31 implement `FpConfig` directly, keeping elements in canonical form
+//! and reducing products through the special shape of their moduli. BabyBear has no such shape
+//! and uses arkworks' single-limb Montgomery backend.
+
+pub mod baby_bear;
+pub mod goldilocks;
+pub mod mersenne31;
+mod test;
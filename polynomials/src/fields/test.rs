@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod test {
+    use crate::fields::baby_bear::{BabyBear, BabyBearExt2, BabyBearExt4};
+    use crate::fields::goldilocks::{Goldilocks, GoldilocksExt2};
+    use crate::fields::mersenne31::{Mersenne31, Mersenne31Ext2, Mersenne31Ext4};
+    use crate::multilinear_polynomial::multilinear::MultilinearPoly;
+    use crate::univariate_polynomial::domain::EvaluationDomain;
+    use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+    use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+    use ark_ff::{FftField, Field, PrimeField};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use ark_std::UniformRand;
+
+    // Arithmetic laws of the field, including the Frobenius coefficients of its configuration.
+    fn check_field<F: Field>() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let (a, b) = (F::rand(&mut rng), F::rand(&mut rng));
+            assert_eq!((a + b) * (a - b), a.square() - b.square());
+            if !a.is_zero() {
+                assert_eq!(a * a.inverse().unwrap(), F::one());
+            }
+            let p = F::BasePrimeField::MODULUS;
+            for power in 0..F::extension_degree() as usize {
+                let mut expected = a;
+                for _ in 0..power {
+                    expected = expected.pow(p);
+                }
+                assert_eq!(a.frobenius_map(power), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_arithmetic() {
+        check_field::<Goldilocks>();
+        check_field::<GoldilocksExt2>();
+        check_field::<BabyBear>();
+        check_field::<BabyBearExt2>();
+        check_field::<BabyBearExt4>();
+        check_field::<Mersenne31>();
+        check_field::<Mersenne31Ext2>();
+        check_field::<Mersenne31Ext4>();
+
+        assert_eq!(-Goldilocks::from(1u64), Goldilocks::from(u64::MAX - (1 << 32) + 1));
+        assert_eq!(Mersenne31::from(1u64 << 31), Mersenne31::from(1u64));
+        assert_eq!(BabyBear::from(15u64 << 27), -BabyBear::from(1u64));
+    }
+
+    // The same moduli on arkworks' Montgomery backend, as a reference for the dedicated ones.
+    #[derive(MontConfig)]
+    #[modulus = "18446744069414584321"]
+    #[generator = "7"]
+    struct GoldilocksMontConfig;
+    type GoldilocksMont = Fp64<MontBackend<GoldilocksMontConfig, 1>>;
+
+    #[derive(MontConfig)]
+    #[modulus = "2147483647"]
+    #[generator = "7"]
+    struct Mersenne31MontConfig;
+    type Mersenne31Mont = Fp64<MontBackend<Mersenne31MontConfig, 1>>;
+
+    // Every operation agrees with the reference field, on random elements and on values next to
+    // the reduction boundaries.
+    fn check_against_reference<F, R>(edges: &[u64])
+    where
+        F: PrimeField,
+        R: PrimeField<BigInt = F::BigInt>,
+    {
+        let same = |a: F, b: R| a.into_bigint() == b.into_bigint();
+        let mut rng = StdRng::seed_from_u64(4);
+        let mut values: Vec<u64> = edges.to_vec();
+        values.extend((0..50).map(|_| F::rand(&mut rng).into_bigint().as_ref()[0]));
+
+        assert_eq!(F::MODULUS, R::MODULUS);
+        assert!(same(F::GENERATOR, R::GENERATOR));
+        assert!(same(F::TWO_ADIC_ROOT_OF_UNITY, R::TWO_ADIC_ROOT_OF_UNITY));
+        for &x in &values {
+            let (a, ra) = (F::from(x), R::from(x));
+            assert!(same(a, ra));
+            assert!(same(-a, -ra));
+            assert!(same(a.double(), ra.double()));
+            assert!(same(a.square(), ra.square()));
+            assert_eq!(a.inverse().map(|i| i.into_bigint()), ra.inverse().map(|i| i.into_bigint()));
+            assert_eq!(a.sqrt().is_some(), ra.sqrt().is_some());
+            if let Some(root) = a.sqrt() {
+                assert_eq!(root.square(), a);
+            }
+            for &y in &values {
+                let (b, rb) = (F::from(y), R::from(y));
+                assert!(same(a + b, ra + rb));
+                assert!(same(a - b, ra - rb));
+                assert!(same(a * b, ra * rb));
+            }
+        }
+        assert!(F::from_bigint(F::MODULUS).is_none());
+    }
+
+    #[test]
+    fn test_dedicated_reduction_matches_montgomery() {
+        let p = Goldilocks::MODULUS.as_ref()[0];
+        check_against_reference::<Goldilocks, GoldilocksMont>(&[
+            0,
+            1,
+            2,
+            (1 << 32) - 1,
+            1 << 32,
+            (1 << 32) + 1,
+            1 << 63,
+            p - (1 << 32),
+            p - 2,
+            p - 1,
+        ]);
+        let p = Mersenne31::MODULUS.as_ref()[0];
+        check_against_reference::<Mersenne31, Mersenne31Mont>(&[
+            0,
+            1,
+            2,
+            1 << 30,
+            p - 2,
+            p - 1,
+        ]);
+    }
+
+    #[test]
+    fn test_two_adicity() {
+        assert_eq!(Goldilocks::TWO_ADICITY, 32);
+        assert_eq!(BabyBear::TWO_ADICITY, 27);
+        assert_eq!(Mersenne31::TWO_ADICITY, 1);
+        assert!(EvaluationDomain::<Goldilocks>::new(1 << 20).is_some());
+        assert!(EvaluationDomain::<BabyBear>::new(1 << 20).is_some());
+        assert!(EvaluationDomain::<Mersenne31>::new(4).is_none());
+    }
+
+    // Products above the NTT threshold where the field has the domains for it, and schoolbook
+    // products otherwise.
+    fn check_dense<F: FftField>() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let a = UnivariatePolyDense::<F>::rand(100, &mut rng);
+        let b = UnivariatePolyDense::<F>::rand(70, &mut rng);
+        let product = &a * &b;
+        assert_eq!(product.degree(), 170);
+
+        let x = F::rand(&mut rng);
+        assert_eq!(product.evaluate(x), a.evaluate(x) * b.evaluate(x));
+        assert_eq!(&product / &b, a);
+    }
+
+    #[test]
+    fn test_dense_over_small_fields() {
+        check_dense::<Goldilocks>();
+        check_dense::<GoldilocksExt2>();
+        check_dense::<BabyBear>();
+        check_dense::<BabyBearExt4>();
+        check_dense::<Mersenne31>();
+        check_dense::<Mersenne31Ext2>();
+        check_dense::<Mersenne31Ext4>();
+    }
+
+    fn check_multilinear<F: Field>() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let poly = MultilinearPoly::<F>::rand(5, &mut rng);
+        let point: Vec<F> = (0..5).map(|_| F::rand(&mut rng)).collect();
+
+        let folded = point
+            .iter()
+            .fold(poly.clone(), |acc, r| acc.partial_evaluate((0, *r)));
+        assert_eq!(folded.evaluations, vec![poly.evaluate(point)]);
+
+        // Hypercube points read back the table.
+        let corner = vec![F::one(), F::zero(), F::one(), F::one(), F::zero()];
+        assert_eq!(poly.evaluate(corner), poly.evaluations[0b01101]);
+    }
+
+    #[test]
+    fn test_multilinear_over_small_fields() {
+        check_multilinear::<Goldilocks>();
+        check_multilinear::<BabyBear>();
+        check_multilinear::<BabyBearExt4>();
+        check_multilinear::<Mersenne31>();
+        check_multilinear::<Mersenne31Ext4>();
+
+        // Lifting into the extension commutes with evaluation at base field points.
+        let mut rng = StdRng::seed_from_u64(3);
+        let poly = MultilinearPoly::<Goldilocks>::rand(3, &mut rng);
+        let point: Vec<Goldilocks> = (0..3).map(|_| Goldilocks::rand(&mut rng)).collect();
+        let lifted: MultilinearPoly<GoldilocksExt2> = poly.to_extension();
+        let embedded = point.iter().map(|x| GoldilocksExt2::from_base_prime_field(*x));
+        assert_eq!(
+            lifted.evaluate(embedded.collect()),
+            GoldilocksExt2::from_base_prime_field(poly.evaluate(point))
+        );
+    }
+}
@@ -1,2 +1,16 @@
+#![allow(clippy::module_inception)]
+
+#[macro_use]
+mod macros;
+
+pub mod batch;
+pub mod bivariate_polynomial;
+pub mod error;
+pub mod extension;
+pub mod fields;
 pub mod multilinear_polynomial;
-pub mod univariate_polynomial;
\ No newline at end of file
+pub mod multivariate_polynomial;
+pub mod random;
+pub mod serialization;
+pub mod traits;
+pub mod univariate_polynomial;
@@ -0,0 +1,68 @@
+// Derives the owned, mixed and assigning forms of a binary operator from its `&a op &b` impl.
+// `$field` is the bound the polynomial type places on its coefficients.
+macro_rules! forward_binop {
+    ($poly:ident, $field:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<F: $field> $op for $poly<F> {
+            type Output = $poly<F>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<F: $field> $op<&$poly<F>> for $poly<F> {
+            type Output = $poly<F>;
+
+            fn $method(self, rhs: &$poly<F>) -> Self::Output {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<F: $field> $op<$poly<F>> for &$poly<F> {
+            type Output = $poly<F>;
+
+            fn $method(self, rhs: $poly<F>) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<F: $field> $op_assign<&$poly<F>> for $poly<F> {
+            fn $method_assign(&mut self, rhs: &$poly<F>) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+
+        impl<F: $field> $op_assign for $poly<F> {
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+    };
+}
+
+// Derives scalar multiplication by a field element from the type's `scalar_mul`.
+macro_rules! forward_scalar_mul {
+    ($poly:ident, $field:ident) => {
+        impl<F: $field> Mul<F> for &$poly<F> {
+            type Output = $poly<F>;
+
+            fn mul(self, rhs: F) -> Self::Output {
+                self.scalar_mul(&rhs)
+            }
+        }
+
+        impl<F: $field> Mul<F> for $poly<F> {
+            type Output = $poly<F>;
+
+            fn mul(self, rhs: F) -> Self::Output {
+                self.scalar_mul(&rhs)
+            }
+        }
+
+        impl<F: $field> MulAssign<F> for $poly<F> {
+            fn mul_assign(&mut self, rhs: F) {
+                *self = self.scalar_mul(&rhs);
+            }
+        }
+    };
+}
@@ -0,0 +1,34 @@
+use crate::multilinear_polynomial::multilinear::MultilinearPoly;
+use ark_ff::Field;
+
+/// The equality polynomial `eq(x, r) = prod_j (x_j r_j + (1 - x_j)(1 - r_j))` with `r` fixed,
+/// as its table over the Boolean hypercube.
+///
+/// Entry `i` is `prod_j (r_j if bit j of i is set, else 1 - r_j)`: the value at `r` of the
+/// Lagrange basis polynomial of hypercube point `i`, so the inner product of any evaluation
+/// table with this one is that polynomial evaluated at `r`. Built in O(2^n) by doubling the
+/// table once per coordinate.
+pub fn eq_poly<F: Field>(r: &[F]) -> MultilinearPoly<F> {
+    let mut evaluations = Vec::with_capacity(1 << r.len());
+    evaluations.push(F::one());
+    for r_j in r {
+        for i in 0..evaluations.len() {
+            let high = evaluations[i] * r_j;
+            evaluations[i] -= high;
+            evaluations.push(high);
+        }
+    }
+    MultilinearPoly {
+        num_vars: r.len(),
+        evaluations,
+    }
+}
+
+/// `eq(x, r)` at arbitrary field points, in O(n) without building a table.
+pub fn eq_eval<F: Field>(x: &[F], r: &[F]) -> F {
+    assert_eq!(x.len(), r.len(), "Points must have the same number of coordinates");
+    x.iter()
+        .zip(r)
+        .map(|(x_j, r_j)| *x_j * r_j + (F::one() - x_j) * (F::one() - r_j))
+        .product()
+}
@@ -1,2 +1,3 @@
+pub mod eq;
 pub mod multilinear;
 mod test;
\ No newline at end of file
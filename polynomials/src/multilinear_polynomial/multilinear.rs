@@ -1,35 +1,47 @@
-use ark_ff::PrimeField;
+use crate::error::PolynomialError;
+use crate::multilinear_polynomial::eq::eq_poly;
+use ark_ff::Field;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[derive(Debug, Clone)]
-pub struct MultilinearPoly<F: PrimeField> {
+pub struct MultilinearPoly<F: Field> {
     pub num_vars: usize,
     pub evaluations: Vec<F>,
 }
 
-// impl<F: PrimeField> MultilinearPoly<F> {
-impl<F: PrimeField + std::fmt::Display> MultilinearPoly<F> {
-    // fn new(num_vars: usize, evaluations: Vec<F>) -> Result<Self, &'static str> {
+impl<F: Field> MultilinearPoly<F> {
     pub fn new(num_vars: usize, evaluations: Vec<F>) -> Self {
-        // if 2_usize.pow(num_vars as u32) != evaluations.len() {
-        if evaluations.len() != 1 << num_vars {
-            // return Err("Not a valid Boolean hypercube evaluation!");
-            panic!("Not a valid Boolean hypercube evaluation!");
-        };
+        Self::try_new(num_vars, evaluations).unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        // Ok(Self {
-        //     num_vars,
-        //     evaluations,
-        // })
+    /// Like [`Self::new`], but reports a table whose length is not `2^num_vars` as an error.
+    pub fn try_new(num_vars: usize, evaluations: Vec<F>) -> Result<Self, PolynomialError> {
+        if num_vars >= usize::BITS as usize || evaluations.len() != 1 << num_vars {
+            return Err(PolynomialError::InvalidEvaluationCount {
+                num_vars,
+                len: evaluations.len(),
+            });
+        }
 
-        Self { num_vars, evaluations }
+        Ok(Self { num_vars, evaluations })
     }
-    
-    pub fn partial_evaluate(&self, (position, value): (usize, F)) -> Self {
+
+    pub fn partial_evaluate(&self, assignment: (usize, F)) -> Self {
+        self.try_partial_evaluate(assignment)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`Self::partial_evaluate`], but reports an out-of-range position as an error.
+    pub fn try_partial_evaluate(
+        &self,
+        (position, value): (usize, F),
+    ) -> Result<Self, PolynomialError> {
         if position >= self.num_vars {
-            panic!(
-                "Position {} is out of range for a polynomial with {} variables",
-                position, self.num_vars
-            );
+            return Err(PolynomialError::PositionOutOfRange {
+                position,
+                num_vars: self.num_vars,
+            });
         }
 
         // Use our helper to split the current evaluation vector based on the bit at `position`.
@@ -38,33 +50,64 @@ impl<F: PrimeField + std::fmt::Display> MultilinearPoly<F> {
         // For each pair, compute the combined value.
         let new_evaluations: Vec<F> = vec0
             .into_iter()
-            .zip(vec1.into_iter())
+            .zip(vec1)
             .map(|(a, b)| (F::one() - value) * a + value * b)
             .collect();
 
         // Return a new MultilinearPoly with one fewer free variable.
-        MultilinearPoly {
+        Ok(MultilinearPoly {
             evaluations: new_evaluations,
             num_vars: self.num_vars - 1,
-        }
+        })
     }
 
     pub fn evaluate(&self, assignments: Vec<F>) -> F {
+        self.try_evaluate(assignments)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`Self::evaluate`], but reports a point with the wrong number of coordinates as an
+    /// error.
+    pub fn try_evaluate(&self, assignments: Vec<F>) -> Result<F, PolynomialError> {
         if assignments.len() != self.num_vars {
-            panic!("Number of assignments must equal the number of variables");
+            return Err(PolynomialError::WrongNumberOfAssignments {
+                expected: self.num_vars,
+                actual: assignments.len(),
+            });
         }
-        let mut poly = self.clone();
-        // In each iteration, we fix the first free variable (position 0)
-        // of the current polynomial.
-        for val in assignments {
-            poly = poly.partial_evaluate((0, val));
+        // The polynomial at a point is its inner product with the Lagrange basis there.
+        Ok(self
+            .evaluations
+            .iter()
+            .zip(eq_poly(&assignments).evaluations)
+            .map(|(eval, weight)| weight * eval)
+            .sum())
+    }
+    
+    pub fn scalar_mul(&self, scalar: &F) -> Self {
+        MultilinearPoly {
+            num_vars: self.num_vars,
+            evaluations: self.evaluations.iter().map(|eval| *eval * scalar).collect(),
         }
-        if poly.evaluations.len() != 1 {
-            panic!("Full evaluation did not collapse to a single value");
+    }
+
+    // Combines two tables entry by entry; both sides must have the same number of variables.
+    fn zip_with(&self, rhs: &Self, op: impl Fn(F, F) -> F) -> Self {
+        assert_eq!(
+            self.num_vars, rhs.num_vars,
+            "Polynomials must have the same number of variables"
+        );
+        MultilinearPoly {
+            num_vars: self.num_vars,
+            evaluations: self
+                .evaluations
+                .iter()
+                .zip(&rhs.evaluations)
+                .map(|(a, b)| op(*a, *b))
+                .collect(),
         }
-        poly.evaluations[0]
     }
-    
+
     fn get_paired_evals(evals: &[F], var_index: usize) -> (Vec<F>, Vec<F>) {
         let total = evals.len();
         let mut vec0 = Vec::with_capacity(total / 2);
@@ -90,6 +133,66 @@ impl<F: PrimeField + std::fmt::Display> MultilinearPoly<F> {
     }
 }
 
+impl<F: Field> Add for &MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<F: Field> Sub for &MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+/// The Hadamard product of the evaluation tables. The true product of two multilinear
+/// polynomials has degree up to two in each variable; this is the multilinear polynomial
+/// agreeing with it on the hypercube, which is what sum-check and GKR combine.
+impl<F: Field> Mul for &MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a * b)
+    }
+}
+
+impl<F: Field> Neg for &MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
+
+    fn neg(self) -> Self::Output {
+        MultilinearPoly {
+            num_vars: self.num_vars,
+            evaluations: self.evaluations.iter().map(|eval| -*eval).collect(),
+        }
+    }
+}
+
+impl<F: Field> Neg for MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+forward_binop!(MultilinearPoly, Field, Add, add, AddAssign, add_assign);
+forward_binop!(MultilinearPoly, Field, Sub, sub, SubAssign, sub_assign);
+forward_binop!(MultilinearPoly, Field, Mul, mul, MulAssign, mul_assign);
+forward_scalar_mul!(MultilinearPoly, Field);
+
+/// Panics on an empty iterator, since the number of variables of the sum is unknown.
+impl<F: Field> Sum for MultilinearPoly<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, poly| &acc + &poly)
+            .expect("Cannot sum an empty list of polynomials")
+    }
+}
+
+#[allow(dead_code)]
 fn main() {
     use ark_bn254::Fq;
 
@@ -1,6 +1,8 @@
 
 #[cfg(test)]
 mod test {
+    use crate::error::PolynomialError;
+    use crate::multilinear_polynomial::eq::{eq_eval, eq_poly};
     use crate::multilinear_polynomial::multilinear::MultilinearPoly;
     use ark_bn254::Fq;
 
@@ -139,4 +141,137 @@ mod test {
 
         assert_eq!(result, Fq::from(55));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            MultilinearPoly::try_new(2, vec![Fq::from(0), Fq::from(1), Fq::from(2)]).unwrap_err(),
+            PolynomialError::InvalidEvaluationCount { num_vars: 2, len: 3 }
+        );
+        assert!(MultilinearPoly::<Fq>::try_new(usize::BITS as usize, vec![]).is_err());
+
+        let poly = MultilinearPoly::try_new(2, vec![Fq::from(0), Fq::from(2), Fq::from(3), Fq::from(5)]);
+        assert_eq!(poly.unwrap().evaluations, poly_1().evaluations);
+    }
+
+    #[test]
+    fn test_try_partial_evaluate_and_evaluate() {
+        let poly = poly_1();
+        assert_eq!(
+            poly.try_partial_evaluate((2, Fq::from(1))).unwrap_err(),
+            PolynomialError::PositionOutOfRange { position: 2, num_vars: 2 }
+        );
+        assert_eq!(
+            poly.try_partial_evaluate((1, Fq::from(5))).unwrap().evaluations,
+            poly.partial_evaluate((1, Fq::from(5))).evaluations
+        );
+
+        assert_eq!(
+            poly.try_evaluate(vec![Fq::from(1)]),
+            Err(PolynomialError::WrongNumberOfAssignments { expected: 2, actual: 1 })
+        );
+        assert_eq!(
+            poly.try_evaluate(vec![Fq::from(3), Fq::from(5)]),
+            Ok(poly.evaluate(vec![Fq::from(3), Fq::from(5)]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Position 3 is out of range for a polynomial with 2 variables")]
+    fn test_partial_evaluate_out_of_range() {
+        poly_1().partial_evaluate((3, Fq::from(1)));
+    }
+
+    #[test]
+    fn test_eq_poly() {
+        let r = vec![Fq::from(3), Fq::from(5), Fq::from(7)];
+        let eq = eq_poly(&r);
+        assert_eq!(eq.num_vars, 3);
+
+        // Entry 0b011 sits at x = (1, 1, 0).
+        assert_eq!(eq.evaluations[0b011], Fq::from(3) * Fq::from(5) * (Fq::from(1) - Fq::from(7)));
+        // The basis sums to one, and each entry is the closed form at its hypercube point.
+        assert_eq!(eq.evaluations.iter().sum::<Fq>(), Fq::from(1));
+        for (i, entry) in eq.evaluations.iter().enumerate() {
+            let x: Vec<Fq> = (0..3).map(|j| Fq::from(((i >> j) & 1) as u64)).collect();
+            assert_eq!(eq_eval(&x, &r), *entry);
+        }
+
+        assert_eq!(eq_poly::<Fq>(&[]).evaluations, vec![Fq::from(1)]);
+    }
+
+    #[test]
+    fn test_eq_eval_is_symmetric_extension() {
+        let x = vec![Fq::from(2), Fq::from(9)];
+        let r = vec![Fq::from(4), Fq::from(6)];
+        assert_eq!(eq_eval(&x, &r), eq_eval(&r, &x));
+        assert_eq!(eq_poly(&r).evaluate(x.clone()), eq_eval(&x, &r));
+    }
+
+    #[test]
+    fn test_evaluate_matches_partial_evaluation() {
+        let poly = MultilinearPoly::new(3, (0..8u64).map(|i| Fq::from(i * i + 1)).collect());
+        let point = vec![Fq::from(3), Fq::from(5), Fq::from(11)];
+        let folded = point
+            .iter()
+            .fold(poly.clone(), |acc, r| acc.partial_evaluate((0, *r)));
+        assert_eq!(poly.evaluate(point), folded.evaluations[0]);
+    }
+
+    fn poly_2() -> MultilinearPoly<Fq> {
+        MultilinearPoly::new(2, vec![Fq::from(7), Fq::from(1), Fq::from(4), Fq::from(9)])
+    }
+
+    #[test]
+    fn test_add_sub_neg_scalar_mul() {
+        let (a, b) = (poly_1(), poly_2());
+        assert_eq!(
+            (&a + &b).evaluations,
+            vec![Fq::from(7), Fq::from(3), Fq::from(7), Fq::from(14)]
+        );
+        assert_eq!((&(&a + &b) - &b).evaluations, a.evaluations);
+        assert_eq!((-&a + a.clone()).evaluations, vec![Fq::from(0); 4]);
+        assert_eq!((a.clone() * Fq::from(3)).evaluations, (&(&a + &a) + &a).evaluations);
+
+        let mut acc = a.clone();
+        acc += &b;
+        acc -= b.clone();
+        acc *= Fq::from(2);
+        assert_eq!(acc.evaluations, a.scalar_mul(&Fq::from(2)).evaluations);
+
+        let total: MultilinearPoly<Fq> = vec![a.clone(), b.clone(), a.clone()].into_iter().sum();
+        assert_eq!(total.evaluations, (&(&a + &b) + &a).evaluations);
+    }
+
+    #[test]
+    fn test_hadamard_product() {
+        let (a, b) = (poly_1(), poly_2());
+        let product = &a * &b;
+        assert_eq!(
+            product.evaluations,
+            vec![Fq::from(0), Fq::from(2), Fq::from(12), Fq::from(45)]
+        );
+
+        // GKR-style combination add_i * (W_b + W_c) + mul_i * (W_b * W_c).
+        let (add_i, mul_i) = (poly_1(), poly_2());
+        let (w_b, w_c) = (poly_2(), poly_1());
+        let layer = &add_i * (&w_b + &w_c) + &mul_i * (&w_b * &w_c);
+        for i in 0..4 {
+            let (w_b, w_c) = (w_b.evaluations[i], w_c.evaluations[i]);
+            assert_eq!(
+                layer.evaluations[i],
+                add_i.evaluations[i] * (w_b + w_c) + mul_i.evaluations[i] * w_b * w_c
+            );
+        }
+
+        let mut squared = a.clone();
+        squared *= &a;
+        assert_eq!(squared.evaluations, (&a * &a).evaluations);
+    }
+
+    #[test]
+    #[should_panic(expected = "Polynomials must have the same number of variables")]
+    fn test_add_mismatched_num_vars() {
+        let _ = poly_1() + MultilinearPoly::new(1, vec![Fq::from(1), Fq::from(2)]);
+    }
+}
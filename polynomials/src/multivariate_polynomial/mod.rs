@@ -0,0 +1,2 @@
+pub mod multivariate;
+mod test;
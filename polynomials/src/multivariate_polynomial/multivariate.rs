@@ -0,0 +1,251 @@
+use crate::multilinear_polynomial::multilinear::MultilinearPoly;
+use ark_ff::{Field, Zero};
+use std::collections::BTreeMap;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// ============= STRUCTS =============
+/// `(coefficient, exponents)` terms, where `exponents[i]` is the power of `x_i` and every
+/// exponent vector has `num_vars` entries. The representation is always canonical: terms are
+/// sorted by descending exponent vector (lexicographically), exponent vectors are unique and
+/// coefficients are non-zero. The fields are private so that this holds; read them through
+/// [`MultivariatePoly::num_vars`] and [`MultivariatePoly::terms`].
+///
+/// Operands with different `num_vars` are combined as if the smaller one ignored the extra
+/// trailing variables.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MultivariatePoly<F: Field> {
+    pub(crate) num_vars: usize,
+    pub(crate) terms: Vec<(F, Vec<usize>)>,
+}
+
+// ============= IMPLEMENTATIONS =============
+
+impl<F: Field> MultivariatePoly<F> {
+    pub fn new(num_vars: usize, terms: Vec<(F, Vec<usize>)>) -> Self {
+        assert!(
+            terms.iter().all(|(_, exponents)| exponents.len() == num_vars),
+            "Every term needs one exponent per variable"
+        );
+
+        let mut merged: BTreeMap<Vec<usize>, F> = BTreeMap::new();
+        for (coeff, exponents) in terms {
+            *merged.entry(exponents).or_insert_with(F::zero) += coeff;
+        }
+        Self::from_map(num_vars, merged)
+    }
+
+    fn from_map(num_vars: usize, terms: BTreeMap<Vec<usize>, F>) -> Self {
+        MultivariatePoly {
+            num_vars,
+            terms: terms
+                .into_iter()
+                .rev()
+                .filter(|(_, coeff)| !coeff.is_zero())
+                .map(|(exponents, coeff)| (coeff, exponents))
+                .collect(),
+        }
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// The canonical `(coefficient, exponents)` terms, in descending order of exponent vector.
+    pub fn terms(&self) -> &[(F, Vec<usize>)] {
+        &self.terms
+    }
+
+    // `exponents` padded with zeros to `num_vars` variables.
+    fn padded(exponents: &[usize], num_vars: usize) -> Vec<usize> {
+        let mut padded = exponents.to_vec();
+        padded.resize(num_vars, 0);
+        padded
+    }
+
+    /// Highest power of `x_var` appearing in any term.
+    pub fn degree(&self, var: usize) -> usize {
+        assert!(
+            var < self.num_vars,
+            "Variable {} is out of range for a polynomial with {} variables",
+            var,
+            self.num_vars
+        );
+        self.terms
+            .iter()
+            .map(|(_, exponents)| exponents[var])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Highest sum of exponents of any term.
+    pub fn total_degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|(_, exponents)| exponents.iter().sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(
+            point.len(),
+            self.num_vars,
+            "Number of assignments must equal the number of variables"
+        );
+        self.terms
+            .iter()
+            .map(|(coeff, exponents)| {
+                exponents
+                    .iter()
+                    .zip(point)
+                    .fold(*coeff, |acc, (exponent, x)| acc * x.pow([*exponent as u64]))
+            })
+            .sum()
+    }
+
+    /// Fixes `x_position = value`, removing that variable; the remaining variables keep their
+    /// order.
+    pub fn partial_evaluate(&self, (position, value): (usize, F)) -> Self {
+        assert!(
+            position < self.num_vars,
+            "Position {} is out of range for a polynomial with {} variables",
+            position,
+            self.num_vars
+        );
+
+        Self::new(
+            self.num_vars - 1,
+            self.terms
+                .iter()
+                .map(|(coeff, exponents)| {
+                    let mut remaining = exponents.clone();
+                    let exponent = remaining.remove(position);
+                    (*coeff * value.pow([exponent as u64]), remaining)
+                })
+                .collect(),
+        )
+    }
+
+    pub fn scalar_mul(&self, scalar: &F) -> Self {
+        Self::new(
+            self.num_vars,
+            self.terms
+                .iter()
+                .map(|(coeff, exponents)| (*coeff * scalar, exponents.clone()))
+                .collect(),
+        )
+    }
+
+    /// The evaluation table over the Boolean hypercube, which determines the polynomial exactly
+    /// when no variable appears with degree above one. Returns `None` otherwise.
+    pub fn to_multilinear(&self) -> Option<MultilinearPoly<F>> {
+        // Coefficient of each multilinear monomial, indexed by the set of variables it contains
+        // (variable `i` is bit `i`, matching `MultilinearPoly`'s layout).
+        let mut evaluations = vec![F::zero(); 1 << self.num_vars];
+        for (coeff, exponents) in &self.terms {
+            let mut monomial = 0;
+            for (var, exponent) in exponents.iter().enumerate() {
+                match exponent {
+                    0 => {}
+                    1 => monomial |= 1 << var,
+                    _ => return None,
+                }
+            }
+            evaluations[monomial] += coeff;
+        }
+
+        // A monomial is 1 exactly on the points containing all its variables, so each point's
+        // value is the sum over the subsets of its set bits.
+        for var in 0..self.num_vars {
+            for point in 0..evaluations.len() {
+                if point & (1 << var) != 0 {
+                    let without_var = evaluations[point ^ (1 << var)];
+                    evaluations[point] += without_var;
+                }
+            }
+        }
+
+        Some(MultilinearPoly::new(self.num_vars, evaluations))
+    }
+}
+
+impl<F: Field> Add for &MultivariatePoly<F> {
+    type Output = MultivariatePoly<F>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let num_vars = self.num_vars.max(rhs.num_vars);
+        let mut sums: BTreeMap<Vec<usize>, F> = BTreeMap::new();
+        for (coeff, exponents) in self.terms.iter().chain(&rhs.terms) {
+            *sums
+                .entry(MultivariatePoly::<F>::padded(exponents, num_vars))
+                .or_insert_with(F::zero) += coeff;
+        }
+        MultivariatePoly::from_map(num_vars, sums)
+    }
+}
+
+impl<F: Field> Mul for &MultivariatePoly<F> {
+    type Output = MultivariatePoly<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let num_vars = self.num_vars.max(rhs.num_vars);
+        let mut products: BTreeMap<Vec<usize>, F> = BTreeMap::new();
+        for (l_coeff, l_exponents) in &self.terms {
+            let l_exponents = MultivariatePoly::<F>::padded(l_exponents, num_vars);
+            for (r_coeff, r_exponents) in &rhs.terms {
+                let mut exponents = l_exponents.clone();
+                for (exponent, r_exponent) in exponents.iter_mut().zip(r_exponents) {
+                    *exponent += r_exponent;
+                }
+                *products.entry(exponents).or_insert_with(F::zero) += *l_coeff * r_coeff;
+            }
+        }
+        MultivariatePoly::from_map(num_vars, products)
+    }
+}
+
+impl<F: Field> Sub for &MultivariatePoly<F> {
+    type Output = MultivariatePoly<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl<F: Field> Neg for &MultivariatePoly<F> {
+    type Output = MultivariatePoly<F>;
+
+    fn neg(self) -> Self::Output {
+        MultivariatePoly {
+            num_vars: self.num_vars,
+            terms: self
+                .terms
+                .iter()
+                .map(|(coeff, exponents)| (-*coeff, exponents.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<F: Field> Neg for MultivariatePoly<F> {
+    type Output = MultivariatePoly<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+forward_binop!(MultivariatePoly, Field, Add, add, AddAssign, add_assign);
+forward_binop!(MultivariatePoly, Field, Sub, sub, SubAssign, sub_assign);
+forward_binop!(MultivariatePoly, Field, Mul, mul, MulAssign, mul_assign);
+forward_scalar_mul!(MultivariatePoly, Field);
+
+impl<F: Field> Zero for MultivariatePoly<F> {
+    fn zero() -> Self {
+        MultivariatePoly::new(0, vec![])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.iter().all(|(coeff, _)| coeff.is_zero())
+    }
+}
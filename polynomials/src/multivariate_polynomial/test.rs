@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod test {
+    use crate::multivariate_polynomial::multivariate::MultivariatePoly;
+    use ark_bn254::Fr;
+    use ark_ff::Zero;
+
+    // 2 x0^3 + x0 x2 + x1 x2
+    fn poly_1() -> MultivariatePoly<Fr> {
+        MultivariatePoly::new(
+            3,
+            vec![
+                (Fr::from(2), vec![3, 0, 0]),
+                (Fr::from(1), vec![1, 0, 1]),
+                (Fr::from(1), vec![0, 1, 1]),
+            ],
+        )
+    }
+
+    // 3 x0 x1 + 5 x2 + 7
+    fn poly_2() -> MultivariatePoly<Fr> {
+        MultivariatePoly::new(
+            3,
+            vec![
+                (Fr::from(7), vec![0, 0, 0]),
+                (Fr::from(3), vec![1, 1, 0]),
+                (Fr::from(5), vec![0, 0, 1]),
+            ],
+        )
+    }
+
+    fn point() -> Vec<Fr> {
+        vec![Fr::from(2), Fr::from(3), Fr::from(6)]
+    }
+
+    #[test]
+    fn test_new_is_canonical() {
+        let poly = MultivariatePoly::new(
+            2,
+            vec![
+                (Fr::from(1), vec![0, 1]),
+                (Fr::from(4), vec![2, 0]),
+                (Fr::from(2), vec![0, 1]),
+                (Fr::from(0), vec![1, 1]),
+            ],
+        );
+        assert_eq!(
+            poly.terms(),
+            vec![(Fr::from(4), vec![2, 0]), (Fr::from(3), vec![0, 1])]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_and_degrees() {
+        let poly = poly_1();
+        // 2 * 8 + 2 * 6 + 3 * 6
+        assert_eq!(poly.evaluate(&point()), Fr::from(46));
+        assert_eq!((poly.degree(0), poly.degree(1), poly.degree(2)), (3, 1, 1));
+        assert_eq!(poly.total_degree(), 3);
+    }
+
+    #[test]
+    fn test_partial_evaluate() {
+        let poly = poly_1();
+        let partial = poly.partial_evaluate((1, Fr::from(3)));
+        assert_eq!(partial.num_vars(), 2);
+        assert_eq!(
+            partial.evaluate(&[Fr::from(2), Fr::from(6)]),
+            poly.evaluate(&point())
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (poly_1(), poly_2());
+        let p = point();
+
+        assert_eq!((&a + &b).evaluate(&p), a.evaluate(&p) + b.evaluate(&p));
+        assert_eq!((&a - &b).evaluate(&p), a.evaluate(&p) - b.evaluate(&p));
+        assert_eq!((&a * &b).evaluate(&p), a.evaluate(&p) * b.evaluate(&p));
+        assert_eq!((&a * &b).total_degree(), 5);
+        assert!((&a - &a).is_zero());
+
+        // Zero has no variables but combines with polynomials of any arity.
+        assert_eq!(&MultivariatePoly::zero() + &a, a);
+        assert!((&a * &MultivariatePoly::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_to_multilinear() {
+        let poly = poly_2();
+        let multilinear = poly.to_multilinear().unwrap();
+        assert_eq!(multilinear.num_vars, 3);
+        for index in 0..8usize {
+            let point: Vec<Fr> = (0..3)
+                .map(|var| Fr::from(((index >> var) & 1) as u64))
+                .collect();
+            assert_eq!(multilinear.evaluations[index], poly.evaluate(&point));
+        }
+        assert_eq!(multilinear.evaluate(point()), poly.evaluate(&point()));
+
+        assert!(poly_1().to_multilinear().is_none());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::multilinear_polynomial::multilinear::MultilinearPoly;
+use crate::univariate_polynomial::univariate::{UnivariatePolyDense, UnivariatePolySparse};
+use ark_ff::{FftField, Field};
+use ark_std::collections::BTreeSet;
+use ark_std::rand::{Rng, RngCore};
+
+fn rand_non_zero<F: Field, R: RngCore>(rng: &mut R) -> F {
+    loop {
+        let value = F::rand(rng);
+        if !value.is_zero() {
+            return value;
+        }
+    }
+}
+
+// ============= DENSE =============
+
+impl<F: FftField> UnivariatePolyDense<F> {
+    /// Samples a polynomial of exactly `degree`: every coefficient is uniform, except the leading
+    /// one which is uniform over the non-zero elements.
+    pub fn rand<R: RngCore>(degree: usize, rng: &mut R) -> Self {
+        let constant = if degree == 0 {
+            rand_non_zero(rng)
+        } else {
+            F::rand(rng)
+        };
+        Self::rand_with_constant(degree, constant, rng)
+    }
+
+    /// Like [`Self::rand`] but with the constant term fixed, e.g. to hide a secret at zero. For
+    /// `degree == 0` this is just the constant.
+    pub fn rand_with_constant<R: RngCore>(degree: usize, constant: F, rng: &mut R) -> Self {
+        let mut coefficient = Vec::with_capacity(degree + 1);
+        coefficient.push(constant);
+        if degree > 0 {
+            coefficient.extend((1..degree).map(|_| F::rand(rng)));
+            coefficient.push(rand_non_zero(rng));
+        }
+        Self::new(coefficient)
+    }
+}
+
+// ============= SPARSE =============
+
+impl<F: Field> UnivariatePolySparse<F> {
+    /// Samples a polynomial of exactly `degree` with `num_terms` non-zero coefficients. The
+    /// leading term is always present; the other degrees are drawn uniformly from `0..degree`.
+    pub fn rand<R: RngCore>(degree: usize, num_terms: usize, rng: &mut R) -> Self {
+        assert!(
+            (1..=degree.saturating_add(1)).contains(&num_terms),
+            "A degree {} polynomial has between 1 and {} terms",
+            degree,
+            degree.saturating_add(1)
+        );
+
+        // Floyd's algorithm: a uniform (num_terms - 1)-subset of 0..degree without rejection.
+        // The assert above keeps `degree - (num_terms - 1)` from overflowing.
+        let mut degrees = BTreeSet::new();
+        for bound in degree - (num_terms - 1)..degree {
+            let candidate = rng.gen_range(0..=bound);
+            if !degrees.insert(candidate) {
+                degrees.insert(bound);
+            }
+        }
+        degrees.insert(degree);
+
+        Self::new(
+            degrees
+                .into_iter()
+                .map(|degree| (rand_non_zero(rng), degree))
+                .collect(),
+        )
+    }
+}
+
+// ============= MULTILINEAR =============
+
+impl<F: Field> MultilinearPoly<F> {
+    /// Samples a multilinear polynomial with uniform evaluations over the hypercube.
+    pub fn rand<R: RngCore>(num_vars: usize, rng: &mut R) -> Self {
+        MultilinearPoly {
+            num_vars,
+            evaluations: (0..1usize << num_vars).map(|_| F::rand(rng)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_rand_dense_has_exact_degree() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for degree in [0, 1, 7, 40] {
+            let poly = UnivariatePolyDense::<Fr>::rand(degree, &mut rng);
+            assert_eq!(poly.coefficient.len(), degree + 1);
+        }
+
+        let secret = Fr::from(42);
+        let masked = UnivariatePolyDense::rand_with_constant(5, secret, &mut rng);
+        assert_eq!(masked.degree(), 5);
+        assert_eq!(masked.evaluate(Fr::from(0)), secret);
+    }
+
+    #[test]
+    fn test_rand_sparse_term_count() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for (degree, num_terms) in [(0, 1), (1 << 40, 3), (10, 11), (10, 1), (usize::MAX, 2)] {
+            let poly = UnivariatePolySparse::<Fr>::rand(degree, num_terms, &mut rng);
+            assert_eq!(poly.degree(), degree);
+            assert_eq!(poly.coefficient.len(), num_terms);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "A degree 2 polynomial has between 1 and 3 terms")]
+    fn test_rand_sparse_too_many_terms() {
+        UnivariatePolySparse::<Fr>::rand(2, 4, &mut StdRng::seed_from_u64(3));
+    }
+
+    #[test]
+    fn test_rand_is_reproducible_from_seed() {
+        let sample = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (
+                UnivariatePolyDense::<Fr>::rand(8, &mut rng),
+                MultilinearPoly::<Fr>::rand(3, &mut rng).evaluations,
+            )
+        };
+        assert_eq!(sample(4), sample(4));
+        assert_ne!(sample(4), sample(5));
+        assert_eq!(sample(4).1.len(), 8);
+    }
+}
@@ -0,0 +1,281 @@
+use crate::multilinear_polynomial::multilinear::MultilinearPoly;
+use crate::univariate_polynomial::univariate::{UnivariatePolyDense, UnivariatePolySparse};
+use ark_ff::{FftField, Field};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+
+// ============= DENSE =============
+
+impl<F: FftField> CanonicalSerialize for UnivariatePolyDense<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.coefficient.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.coefficient.serialized_size(compress)
+    }
+}
+
+impl<F: FftField> Valid for UnivariatePolyDense<F> {
+    // Only canonical encodings are accepted: no trailing zero coefficients.
+    fn check(&self) -> Result<(), SerializationError> {
+        match self.coefficient.last() {
+            Some(leading) if leading.is_zero() => Err(SerializationError::InvalidData),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for UnivariatePolyDense<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let coefficient = Vec::<F>::deserialize_with_mode(reader, compress, validate)?;
+        let poly = UnivariatePolyDense { coefficient };
+        if validate == Validate::Yes {
+            poly.check()?;
+        }
+        // Unchecked input is still normalised, since the rest of the crate relies on it.
+        Ok(UnivariatePolyDense::new(poly.coefficient))
+    }
+}
+
+// ============= SPARSE =============
+
+impl<F: Field> CanonicalSerialize for UnivariatePolySparse<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.coefficient.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.coefficient.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for UnivariatePolySparse<F> {
+    // Terms must be non-zero and strictly descending in degree.
+    fn check(&self) -> Result<(), SerializationError> {
+        let non_zero = self.coefficient.iter().all(|(coeff, _)| !coeff.is_zero());
+        let descending = self.coefficient.windows(2).all(|pair| pair[0].1 > pair[1].1);
+        if non_zero && descending {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for UnivariatePolySparse<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let coefficient = Vec::<(F, usize)>::deserialize_with_mode(reader, compress, validate)?;
+        let poly = UnivariatePolySparse { coefficient };
+        if validate == Validate::Yes {
+            poly.check()?;
+            return Ok(poly);
+        }
+        // Unchecked input is still normalised, since arithmetic relies on the term order.
+        Ok(UnivariatePolySparse::new(poly.coefficient))
+    }
+}
+
+// ============= MULTILINEAR =============
+
+impl<F: Field> CanonicalSerialize for MultilinearPoly<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.num_vars.serialize_with_mode(&mut writer, compress)?;
+        self.evaluations.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.num_vars.serialized_size(compress) + self.evaluations.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for MultilinearPoly<F> {
+    // The table must cover the whole Boolean hypercube.
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.num_vars < usize::BITS as usize && self.evaluations.len() == 1 << self.num_vars {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for MultilinearPoly<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let num_vars = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let evaluations = Vec::<F>::deserialize_with_mode(&mut reader, compress, validate)?;
+        // The length check is O(1), so it runs even for unchecked input: every operation on the
+        // polynomial shifts or indexes by `num_vars`.
+        MultilinearPoly::try_new(num_vars, evaluations).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+// ============= SERDE =============
+
+// Serde support goes through the compressed canonical encoding, so both formats accept
+// exactly the same polynomials.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_via_canonical {
+    ($poly:ident, $field:ident) => {
+        impl<F: $field> serde::Serialize for $poly<F> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut bytes = Vec::with_capacity(self.compressed_size());
+                self.serialize_compressed(&mut bytes)
+                    .map_err(serde::ser::Error::custom)?;
+                serializer.serialize_bytes(&bytes)
+            }
+        }
+
+        impl<'de, F: $field> serde::Deserialize<'de> for $poly<F> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let bytes = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
+                Self::deserialize_compressed(bytes.as_slice()).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_serde_via_canonical!(UnivariatePolyDense, FftField);
+#[cfg(feature = "serde")]
+impl_serde_via_canonical!(UnivariatePolySparse, Field);
+#[cfg(feature = "serde")]
+impl_serde_via_canonical!(MultilinearPoly, Field);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    fn dense() -> UnivariatePolyDense<Fr> {
+        UnivariatePolyDense::new(vec![Fr::from(1), Fr::from(2), Fr::from(3)])
+    }
+
+    fn sparse() -> UnivariatePolySparse<Fr> {
+        UnivariatePolySparse::new(vec![(Fr::from(5), 11), (Fr::from(4), 1), (Fr::from(3), 0)])
+    }
+
+    fn multilinear() -> MultilinearPoly<Fr> {
+        MultilinearPoly::new(2, vec![Fr::from(0), Fr::from(2), Fr::from(3), Fr::from(5)])
+    }
+
+    fn round_trip<T: CanonicalSerialize + CanonicalDeserialize>(value: &T) -> (T, T) {
+        let mut compressed = vec![];
+        value.serialize_compressed(&mut compressed).unwrap();
+        assert_eq!(compressed.len(), value.compressed_size());
+
+        let mut uncompressed = vec![];
+        value.serialize_uncompressed(&mut uncompressed).unwrap();
+        assert_eq!(uncompressed.len(), value.uncompressed_size());
+
+        (
+            T::deserialize_compressed(compressed.as_slice()).unwrap(),
+            T::deserialize_uncompressed(uncompressed.as_slice()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_round_trip_all_types() {
+        assert_eq!(round_trip(&dense()), (dense(), dense()));
+        assert_eq!(round_trip(&sparse()), (sparse(), sparse()));
+
+        let (compressed, uncompressed) = round_trip(&multilinear());
+        for poly in [compressed, uncompressed] {
+            assert_eq!(poly.num_vars, 2);
+            assert_eq!(poly.evaluations, multilinear().evaluations);
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_canonical_dense() {
+        let padded = UnivariatePolyDense {
+            coefficient: vec![Fr::from(1), Fr::from(0)],
+        };
+        let mut bytes = vec![];
+        padded.serialize_compressed(&mut bytes).unwrap();
+
+        assert!(UnivariatePolyDense::<Fr>::deserialize_compressed(bytes.as_slice()).is_err());
+        assert_eq!(
+            UnivariatePolyDense::<Fr>::deserialize_compressed_unchecked(bytes.as_slice()).unwrap(),
+            UnivariatePolyDense::new(vec![Fr::from(1)])
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_sparse_and_multilinear() {
+        let unsorted = UnivariatePolySparse {
+            coefficient: vec![(Fr::from(1), 0), (Fr::from(1), 3)],
+        };
+        let mut bytes = vec![];
+        unsorted.serialize_compressed(&mut bytes).unwrap();
+        assert!(UnivariatePolySparse::<Fr>::deserialize_compressed(bytes.as_slice()).is_err());
+        let normalised =
+            UnivariatePolySparse::<Fr>::deserialize_compressed_unchecked(bytes.as_slice()).unwrap();
+        assert_eq!(normalised.terms(), &[(Fr::from(1), 3), (Fr::from(1), 0)]);
+        assert_eq!(normalised.evaluate(Fr::from(2)), Fr::from(9));
+
+        let short_table = MultilinearPoly {
+            num_vars: 3,
+            evaluations: vec![Fr::from(1); 4],
+        };
+        let mut bytes = vec![];
+        short_table.serialize_uncompressed(&mut bytes).unwrap();
+        assert!(MultilinearPoly::<Fr>::deserialize_uncompressed(bytes.as_slice()).is_err());
+        assert!(
+            MultilinearPoly::<Fr>::deserialize_uncompressed_unchecked(bytes.as_slice()).is_err()
+        );
+
+        // A variable count that only matches the table length after truncation to 32 bits.
+        let wrapped = MultilinearPoly {
+            num_vars: (1 << 32) + 2,
+            evaluations: vec![Fr::from(1); 4],
+        };
+        assert!(wrapped.check().is_err());
+        let mut bytes = vec![];
+        wrapped.serialize_compressed(&mut bytes).unwrap();
+        assert!(MultilinearPoly::<Fr>::deserialize_compressed(bytes.as_slice()).is_err());
+        assert!(MultilinearPoly::<Fr>::deserialize_compressed_unchecked(bytes.as_slice()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&sparse()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<UnivariatePolySparse<Fr>>(&json).unwrap(),
+            sparse()
+        );
+
+        let json = serde_json::to_string(&dense()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<UnivariatePolyDense<Fr>>(&json).unwrap(),
+            dense()
+        );
+    }
+}
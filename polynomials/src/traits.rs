@@ -0,0 +1,279 @@
+use crate::bivariate_polynomial::bivariate::BivariatePoly;
+use crate::multilinear_polynomial::multilinear::MultilinearPoly;
+use crate::multivariate_polynomial::multivariate::MultivariatePoly;
+use crate::univariate_polynomial::univariate::{UnivariatePolyDense, UnivariatePolySparse};
+use ark_ff::{FftField, Field};
+use std::fmt::Debug;
+
+/// Behaviour shared by every polynomial representation in the crate, so protocols can be
+/// written against the trait and alternative backends plugged in.
+///
+/// The inherent methods of the same names stay the most convenient way to call a concrete
+/// type; the trait versions take the evaluation point by reference.
+pub trait Polynomial<F: Field>: Clone + Debug {
+    /// What the polynomial is evaluated at: `F` for univariates, a slice for multivariates.
+    type Point: ?Sized;
+
+    fn num_vars(&self) -> usize;
+
+    /// Total degree, taken to be 0 for the zero polynomial.
+    fn degree(&self) -> usize;
+
+    fn evaluate(&self, point: &Self::Point) -> F;
+}
+
+/// A polynomial in one variable, convertible to and from its coefficients in ascending order.
+pub trait UnivariatePolynomial<F: Field>: Polynomial<F, Point = F> {
+    fn from_coefficients(coefficients: Vec<F>) -> Self;
+
+    /// Coefficients in ascending order of degree, without trailing zeros.
+    fn coefficients(&self) -> Vec<F>;
+
+    /// The polynomial of degree below `xs.len()` through every `(xs[i], ys[i])`.
+    fn interpolate(xs: &[F], ys: &[F]) -> Self;
+}
+
+/// A multilinear polynomial described by its evaluations over the Boolean hypercube, where
+/// index `i` of the table is the point whose `j`-th coordinate is bit `j` of `i`.
+pub trait MultilinearExtension<F: Field>: Polynomial<F, Point = [F]> {
+    fn from_evaluations(num_vars: usize, evaluations: Vec<F>) -> Self;
+
+    fn evaluations(&self) -> &[F];
+
+    /// Fixes variable `assignment.0` to `assignment.1`, leaving a polynomial in one fewer
+    /// variable.
+    fn partial_evaluate(&self, assignment: (usize, F)) -> Self;
+}
+
+// ============= UNIVARIATE =============
+
+impl<F: FftField> Polynomial<F> for UnivariatePolyDense<F> {
+    type Point = F;
+
+    fn num_vars(&self) -> usize {
+        1
+    }
+
+    fn degree(&self) -> usize {
+        UnivariatePolyDense::degree(self)
+    }
+
+    fn evaluate(&self, point: &F) -> F {
+        UnivariatePolyDense::evaluate(self, *point)
+    }
+}
+
+impl<F: FftField> UnivariatePolynomial<F> for UnivariatePolyDense<F> {
+    fn from_coefficients(coefficients: Vec<F>) -> Self {
+        UnivariatePolyDense::new(coefficients)
+    }
+
+    fn coefficients(&self) -> Vec<F> {
+        self.coefficient.clone()
+    }
+
+    fn interpolate(xs: &[F], ys: &[F]) -> Self {
+        UnivariatePolyDense::interpolate_fast(xs, ys)
+    }
+}
+
+impl<F: Field> Polynomial<F> for UnivariatePolySparse<F> {
+    type Point = F;
+
+    fn num_vars(&self) -> usize {
+        1
+    }
+
+    fn degree(&self) -> usize {
+        UnivariatePolySparse::degree(self)
+    }
+
+    fn evaluate(&self, point: &F) -> F {
+        UnivariatePolySparse::evaluate(self, *point)
+    }
+}
+
+impl<F: FftField> UnivariatePolynomial<F> for UnivariatePolySparse<F> {
+    fn from_coefficients(coefficients: Vec<F>) -> Self {
+        UnivariatePolySparse::from(UnivariatePolyDense::new(coefficients))
+    }
+
+    fn coefficients(&self) -> Vec<F> {
+        UnivariatePolyDense::from(self).coefficient
+    }
+
+    fn interpolate(xs: &[F], ys: &[F]) -> Self {
+        UnivariatePolySparse::from(UnivariatePolyDense::interpolate_fast(xs, ys))
+    }
+}
+
+// ============= MULTILINEAR =============
+
+impl<F: Field> Polynomial<F> for MultilinearPoly<F> {
+    type Point = [F];
+
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    // The evaluation table hides the monomials, so recover their coefficients with the inverse
+    // of the subset-sum transform; monomial `m` has degree `popcount(m)`.
+    fn degree(&self) -> usize {
+        let mut coefficients = self.evaluations.clone();
+        for var in 0..self.num_vars {
+            for index in 0..coefficients.len() {
+                if index & (1 << var) != 0 {
+                    let without_var = coefficients[index ^ (1 << var)];
+                    coefficients[index] -= without_var;
+                }
+            }
+        }
+
+        coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(monomial, _)| monomial.count_ones() as usize)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn evaluate(&self, point: &[F]) -> F {
+        MultilinearPoly::evaluate(self, point.to_vec())
+    }
+}
+
+impl<F: Field> MultilinearExtension<F> for MultilinearPoly<F> {
+    fn from_evaluations(num_vars: usize, evaluations: Vec<F>) -> Self {
+        MultilinearPoly::new(num_vars, evaluations)
+    }
+
+    fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    fn partial_evaluate(&self, assignment: (usize, F)) -> Self {
+        MultilinearPoly::partial_evaluate(self, assignment)
+    }
+}
+
+// ============= BIVARIATE =============
+
+impl<F: FftField> Polynomial<F> for BivariatePoly<F> {
+    type Point = (F, F);
+
+    fn num_vars(&self) -> usize {
+        2
+    }
+
+    fn degree(&self) -> usize {
+        self.coefficients
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, coeff)| !coeff.is_zero())
+                    .map(move |(j, _)| i + j)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn evaluate(&self, (x, y): &(F, F)) -> F {
+        BivariatePoly::evaluate(self, *x, *y)
+    }
+}
+
+// ============= MULTIVARIATE =============
+
+impl<F: Field> Polynomial<F> for MultivariatePoly<F> {
+    type Point = [F];
+
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn degree(&self) -> usize {
+        self.total_degree()
+    }
+
+    fn evaluate(&self, point: &[F]) -> F {
+        MultivariatePoly::evaluate(self, point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    // Generic code written once against the traits.
+    fn sum_over_points<F: FftField, P: UnivariatePolynomial<F>>(poly: &P, xs: &[F]) -> F {
+        xs.iter().map(|x| Polynomial::evaluate(poly, x)).sum()
+    }
+
+    fn sum_over_hypercube<F: Field, P: MultilinearExtension<F>>(poly: &P) -> F {
+        poly.evaluations().iter().copied().sum()
+    }
+
+    #[test]
+    fn test_univariate_backends_agree() {
+        let coefficients = vec![Fr::from(4), Fr::from(0), Fr::from(3)];
+        let dense = UnivariatePolyDense::from_coefficients(coefficients.clone());
+        let sparse = UnivariatePolySparse::from_coefficients(coefficients.clone());
+        let xs: Vec<Fr> = (1..=5).map(Fr::from).collect();
+
+        assert_eq!(sum_over_points(&dense, &xs), sum_over_points(&sparse, &xs));
+        assert_eq!(sparse.coefficients(), coefficients);
+        assert_eq!((Polynomial::degree(&sparse), sparse.num_vars()), (2, 1));
+
+        let ys: Vec<Fr> = xs.iter().map(|x| dense.evaluate(*x)).collect();
+        let interpolated: UnivariatePolySparse<Fr> = UnivariatePolynomial::interpolate(&xs, &ys);
+        assert_eq!(interpolated, sparse);
+
+        // No points give the zero polynomial, as with the inherent `interpolate`.
+        let empty: UnivariatePolyDense<Fr> = UnivariatePolynomial::interpolate(&[], &[]);
+        assert_eq!(empty, UnivariatePolyDense::interpolate(vec![], vec![]));
+        assert!(UnivariatePolynomial::coefficients(&empty).is_empty());
+        let empty: UnivariatePolySparse<Fr> = UnivariatePolynomial::interpolate(&[], &[]);
+        assert_eq!(Polynomial::degree(&empty), 0);
+    }
+
+    #[test]
+    fn test_multilinear_extension() {
+        // 2 + x0 x1 over two variables.
+        let evaluations = vec![Fr::from(2), Fr::from(2), Fr::from(2), Fr::from(3)];
+        let poly = MultilinearPoly::from_evaluations(2, evaluations);
+        assert_eq!(sum_over_hypercube(&poly), Fr::from(9));
+        assert_eq!(Polynomial::degree(&poly), 2);
+        assert_eq!(
+            Polynomial::evaluate(&poly, &[Fr::from(5), Fr::from(7)]),
+            Fr::from(37)
+        );
+        assert_eq!(
+            MultilinearExtension::partial_evaluate(&poly, (0, Fr::from(1))).evaluations(),
+            &[Fr::from(2), Fr::from(3)]
+        );
+    }
+
+    #[test]
+    fn test_multivariate_degrees() {
+        // 1 + xy
+        let bivariate = BivariatePoly::new(vec![
+            vec![Fr::from(1), Fr::from(0)],
+            vec![Fr::from(0), Fr::from(1)],
+        ]);
+        assert_eq!(Polynomial::degree(&bivariate), 2);
+        assert_eq!(
+            Polynomial::evaluate(&bivariate, &(Fr::from(3), Fr::from(4))),
+            Fr::from(13)
+        );
+
+        let multivariate = MultivariatePoly::new(3, vec![(Fr::from(2), vec![3, 0, 1])]);
+        assert_eq!(
+            (multivariate.num_vars(), Polynomial::degree(&multivariate)),
+            (3, 4)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use ark_ff::{batch_inversion, Field};
+
+/// Precomputed barycentric weights `w_i = 1 / prod_{j != i} (x_i - x_j)` for a fixed set of
+/// interpolation nodes.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BarycentricWeights<F: Field> {
+    pub points: Vec<F>,
+    pub weights: Vec<F>,
+}
+
+impl<F: Field> BarycentricWeights<F> {
+    /// Computes the weights for arbitrary distinct nodes in O(n^2).
+    pub fn new(points: &[F]) -> Self {
+        let mut weights: Vec<F> = points
+            .iter()
+            .enumerate()
+            .map(|(i, x_i)| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, x_j)| *x_i - x_j)
+                    .product()
+            })
+            .collect();
+
+        assert!(
+            weights.iter().all(|w: &F| !w.is_zero()),
+            "Interpolation points must be distinct"
+        );
+        batch_inversion(&mut weights);
+
+        BarycentricWeights {
+            points: points.to_vec(),
+            weights,
+        }
+    }
+
+    /// Computes the weights for the nodes `{0, 1, ..., degree}` in O(degree), using
+    /// `w_i = (-1)^(degree - i) / (i! (degree - i)!)`.
+    pub fn consecutive(degree: usize) -> Self {
+        let mut factorials = Vec::with_capacity(degree + 1);
+        let mut factorial = F::one();
+        factorials.push(factorial);
+        for i in 1..=degree {
+            factorial *= F::from(i as u64);
+            factorials.push(factorial);
+        }
+
+        let mut weights: Vec<F> = (0..=degree)
+            .map(|i| {
+                let denominator = factorials[i] * factorials[degree - i];
+                if (degree - i) % 2 == 1 {
+                    -denominator
+                } else {
+                    denominator
+                }
+            })
+            .collect();
+        batch_inversion(&mut weights);
+
+        BarycentricWeights {
+            points: (0..=degree).map(|i| F::from(i as u64)).collect(),
+            weights,
+        }
+    }
+}
+
+/// Evaluates at `z` the polynomial of degree below `ys.len()` taking the values `ys` on the
+/// weights' nodes, without recovering its coefficients.
+pub fn evaluate_from_points<F: Field>(
+    weights: &BarycentricWeights<F>,
+    ys: &[F],
+    z: F,
+) -> F {
+    assert_eq!(
+        ys.len(),
+        weights.points.len(),
+        "Number of values must equal the number of points"
+    );
+
+    if let Some(i) = weights.points.iter().position(|x| *x == z) {
+        return ys[i];
+    }
+
+    let mut differences: Vec<F> = weights.points.iter().map(|x| z - x).collect();
+    let vanishing: F = differences.iter().product();
+    batch_inversion(&mut differences);
+
+    let sum: F = weights
+        .weights
+        .iter()
+        .zip(ys.iter())
+        .zip(differences.iter())
+        .map(|((w, y), inv)| *w * y * inv)
+        .sum();
+
+    vanishing * sum
+}
@@ -0,0 +1,152 @@
+use crate::error::PolynomialError;
+use crate::univariate_polynomial::univariate::{UnivariatePolyDense, UnivariatePolySparse};
+use ark_ff::PrimeField;
+use std::fmt;
+use std::str::FromStr;
+
+// Renders terms given in descending degree as e.g. `3x^4 - x + 7`. Coefficients above
+// (p - 1) / 2 are shown as the negation of their additive inverse.
+fn fmt_terms<F: PrimeField>(
+    f: &mut fmt::Formatter<'_>,
+    terms: impl Iterator<Item = (F, usize)>,
+) -> fmt::Result {
+    let mut first = true;
+    for (coeff, degree) in terms.filter(|(coeff, _)| !coeff.is_zero()) {
+        let negative = coeff.into_bigint() > F::MODULUS_MINUS_ONE_DIV_TWO;
+        let magnitude = if negative { -coeff } else { coeff };
+
+        match (first, negative) {
+            (true, true) => write!(f, "-")?,
+            (true, false) => {}
+            (false, true) => write!(f, " - ")?,
+            (false, false) => write!(f, " + ")?,
+        }
+        first = false;
+
+        if degree == 0 || !magnitude.is_one() {
+            write!(f, "{}", magnitude)?;
+        }
+        match degree {
+            0 => {}
+            1 => write!(f, "x")?,
+            _ => write!(f, "x^{}", degree)?,
+        }
+    }
+
+    if first {
+        write!(f, "0")?;
+    }
+    Ok(())
+}
+
+// Highest exponent the parsers accept. Dense parsing allocates one coefficient per degree, so
+// without a cap a short string like `x^1000000000000` would exhaust memory.
+const MAX_PARSE_DEGREE: usize = 1 << 20;
+
+// Parses the syntax produced by `fmt_terms` into `(coefficient, degree)` terms. Whitespace is
+// ignored, `X` is accepted for `x`, `*` may separate a coefficient from the variable, and
+// repeated degrees are left for the caller to combine.
+fn parse_terms<F: PrimeField>(s: &str) -> Result<Vec<(F, usize)>, PolynomialError> {
+    let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        return Err(PolynomialError::ParseError("empty polynomial".to_string()));
+    }
+
+    let mut terms = vec![];
+    let mut rest = compact.as_str();
+    while !rest.is_empty() {
+        let negative = rest.starts_with('-');
+        if negative || rest.starts_with('+') {
+            rest = &rest[1..];
+        }
+
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let (term, tail) = rest.split_at(end);
+        let (coeff, degree) = parse_term::<F>(term)?;
+        terms.push((if negative { -coeff } else { coeff }, degree));
+        rest = tail;
+    }
+
+    Ok(terms)
+}
+
+fn parse_term<F: PrimeField>(term: &str) -> Result<(F, usize), PolynomialError> {
+    let invalid = || PolynomialError::ParseError(format!("invalid term `{}`", term));
+
+    let (coeff, degree) = match term.find(['x', 'X']) {
+        Some(position) => {
+            // `*` must separate an explicit coefficient from the variable.
+            let coeff = match term[..position].strip_suffix('*') {
+                Some("") => return Err(invalid()),
+                Some(coeff) => coeff,
+                None => &term[..position],
+            };
+            let degree = match &term[position + 1..] {
+                "" => 1,
+                exponent => exponent
+                    .strip_prefix('^')
+                    .and_then(|d| d.parse::<usize>().ok())
+                    .ok_or_else(invalid)?,
+            };
+            (coeff, degree)
+        }
+        None => (term, 0),
+    };
+    if degree > MAX_PARSE_DEGREE {
+        return Err(PolynomialError::ParseError(format!(
+            "degree {} in `{}` exceeds the maximum of {}",
+            degree, term, MAX_PARSE_DEGREE
+        )));
+    }
+
+    let coeff = match coeff {
+        "" if term.contains(['x', 'X']) => F::one(),
+        digits if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) => {
+            F::from_str(digits).map_err(|_| invalid())?
+        }
+        _ => return Err(invalid()),
+    };
+    Ok((coeff, degree))
+}
+
+impl<F: PrimeField> fmt::Display for UnivariatePolyDense<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_terms(
+            f,
+            self.coefficient
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(degree, coeff)| (*coeff, degree)),
+        )
+    }
+}
+
+impl<F: PrimeField> fmt::Display for UnivariatePolySparse<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_terms(f, self.coefficient.iter().copied())
+    }
+}
+
+impl<F: PrimeField> FromStr for UnivariatePolyDense<F> {
+    type Err = PolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = parse_terms::<F>(s)?;
+        let len = terms.iter().map(|(_, degree)| degree + 1).max().unwrap_or(0);
+
+        let mut coefficient = vec![F::zero(); len];
+        for (coeff, degree) in terms {
+            coefficient[degree] += coeff;
+        }
+        Ok(UnivariatePolyDense::new(coefficient))
+    }
+}
+
+impl<F: PrimeField> FromStr for UnivariatePolySparse<F> {
+    type Err = PolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(UnivariatePolySparse::new(parse_terms(s)?))
+    }
+}
@@ -0,0 +1,180 @@
+use ark_ff::{batch_inversion, FftField};
+
+/// A multiplicative subgroup of size 2^k generated by a primitive root of unity, or a coset
+/// `offset * <generator>` of one.
+///
+/// Forward and inverse number-theoretic transforms over the domain convert between the
+/// coefficient form and the evaluation form of a polynomial in O(n log n).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EvaluationDomain<F: FftField> {
+    pub size: usize,
+    pub log_size: u32,
+    pub generator: F,
+    pub generator_inv: F,
+    pub size_inv: F,
+    pub offset: F,
+    pub offset_inv: F,
+}
+
+impl<F: FftField> EvaluationDomain<F> {
+    /// Returns the smallest radix-2 domain holding at least `num_coeffs` points, or `None` if
+    /// the field's two-adic subgroup is too small.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        let size = num_coeffs.max(1).checked_next_power_of_two()?;
+        let log_size = size.trailing_zeros();
+        if log_size > F::TWO_ADICITY {
+            return None;
+        }
+
+        let generator = F::get_root_of_unity(size as u64)?;
+
+        Some(EvaluationDomain {
+            size,
+            log_size,
+            generator,
+            generator_inv: generator.inverse()?,
+            size_inv: F::from(size as u64).inverse()?,
+            offset: F::one(),
+            offset_inv: F::one(),
+        })
+    }
+
+    /// Like `new`, but shifted to the coset `offset * <generator>`. `offset` must lie outside
+    /// the subgroup for the coset to be disjoint from it.
+    pub fn new_coset(num_coeffs: usize, offset: F) -> Option<Self> {
+        let domain = Self::new(num_coeffs)?;
+        Some(EvaluationDomain {
+            offset,
+            offset_inv: offset.inverse()?,
+            ..domain
+        })
+    }
+
+    pub fn is_coset(&self) -> bool {
+        !self.offset.is_one()
+    }
+
+    pub fn element(&self, i: usize) -> F {
+        self.offset * self.generator.pow([i as u64])
+    }
+
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut current = self.offset;
+        for _ in 0..self.size {
+            elements.push(current);
+            current *= self.generator;
+        }
+        elements
+    }
+
+    /// Evaluates the polynomial with the given coefficients at every element of the domain.
+    pub fn fft(&self, coefficients: &[F]) -> Vec<F> {
+        assert!(
+            coefficients.len() <= self.size,
+            "Polynomial has more coefficients than the domain has points"
+        );
+
+        let mut values = coefficients.to_vec();
+        values.resize(self.size, F::zero());
+        if self.is_coset() {
+            Self::scale_by_powers(&mut values, self.offset);
+        }
+        Self::ntt_in_place(&mut values, self.generator);
+        values
+    }
+
+    /// Recovers the coefficients of the polynomial taking `evaluations` over the domain.
+    pub fn ifft(&self, evaluations: &[F]) -> Vec<F> {
+        assert!(
+            evaluations.len() <= self.size,
+            "More evaluations than the domain has points"
+        );
+
+        let mut values = evaluations.to_vec();
+        values.resize(self.size, F::zero());
+        Self::ntt_in_place(&mut values, self.generator_inv);
+        values.iter_mut().for_each(|value| *value *= self.size_inv);
+        if self.is_coset() {
+            Self::scale_by_powers(&mut values, self.offset_inv);
+        }
+        values
+    }
+
+    /// Evaluates the vanishing polynomial `X^n - offset^n` of the domain at `z`.
+    pub fn evaluate_vanishing(&self, z: F) -> F {
+        z.pow([self.size as u64]) - self.offset.pow([self.size as u64])
+    }
+
+    /// Returns the value at `z` of every Lagrange basis polynomial of the domain, so that
+    /// `f(z) = sum_i f(w_i) * L_i(z)` for any `f` of degree below the domain size.
+    pub fn lagrange_coefficients(&self, z: F) -> Vec<F> {
+        let elements = self.elements();
+        if let Some(i) = elements.iter().position(|w| *w == z) {
+            let mut coefficients = vec![F::zero(); self.size];
+            coefficients[i] = F::one();
+            return coefficients;
+        }
+
+        // L_i(z) = Z(z) * w_i / (n * offset^n * (z - w_i)), since Z'(w_i) = n * offset^n / w_i.
+        let mut denominators: Vec<F> = elements.iter().map(|w| z - w).collect();
+        batch_inversion(&mut denominators);
+        let scale = self.evaluate_vanishing(z)
+            * self.size_inv
+            * self.offset_inv.pow([self.size as u64]);
+
+        elements
+            .iter()
+            .zip(denominators.iter())
+            .map(|(w, inv)| scale * w * inv)
+            .collect()
+    }
+
+    // Multiplies the i-th entry by `base^i`.
+    fn scale_by_powers(values: &mut [F], base: F) {
+        let mut power = F::one();
+        for value in values.iter_mut() {
+            *value *= power;
+            power *= base;
+        }
+    }
+
+    // Iterative radix-2 Cooley-Tukey transform. `root` must have order `values.len()`.
+    fn ntt_in_place(values: &mut [F], root: F) {
+        let n = values.len();
+        if n <= 1 {
+            return;
+        }
+        let log_n = n.trailing_zeros();
+
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - log_n);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        let mut half = 1;
+        while half < n {
+            // Twiddle factors for butterflies of width `2 * half`.
+            let step = root.pow([(n / (2 * half)) as u64]);
+            let mut twiddles = Vec::with_capacity(half);
+            let mut w = F::one();
+            for _ in 0..half {
+                twiddles.push(w);
+                w *= step;
+            }
+
+            for chunk in values.chunks_mut(2 * half) {
+                let (lo, hi) = chunk.split_at_mut(half);
+                for ((a, b), w) in lo.iter_mut().zip(hi.iter_mut()).zip(twiddles.iter()) {
+                    let t = *b * w;
+                    *b = *a - t;
+                    *a += t;
+                }
+            }
+
+            half *= 2;
+        }
+    }
+}
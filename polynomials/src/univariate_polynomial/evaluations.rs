@@ -0,0 +1,113 @@
+use crate::univariate_polynomial::domain::EvaluationDomain;
+use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+use ark_ff::FftField;
+use std::ops::{Add, Mul, Sub};
+
+/// A univariate polynomial in Lagrange-basis form: its evaluations over a radix-2 subgroup or
+/// coset.
+///
+/// Pointwise products are only meaningful while the true product degree stays below the
+/// domain size; pick a domain large enough for the expression being computed.
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnivariatePolyEvals<F: FftField> {
+    pub evaluations: Vec<F>,
+    pub domain: EvaluationDomain<F>,
+}
+
+impl<F: FftField> UnivariatePolyEvals<F> {
+    pub fn new(evaluations: Vec<F>, domain: EvaluationDomain<F>) -> Self {
+        assert_eq!(
+            evaluations.len(),
+            domain.size,
+            "Number of evaluations must equal the domain size"
+        );
+        UnivariatePolyEvals {
+            evaluations,
+            domain,
+        }
+    }
+
+    pub fn from_dense(poly: &UnivariatePolyDense<F>, domain: EvaluationDomain<F>) -> Self {
+        UnivariatePolyEvals::new(domain.fft(&poly.coefficient), domain)
+    }
+
+    pub fn to_dense(&self) -> UnivariatePolyDense<F> {
+        UnivariatePolyDense::new(self.domain.ifft(&self.evaluations))
+    }
+
+    /// Evaluates the interpolant at an arbitrary point, inside or outside the domain.
+    pub fn evaluate(&self, z: F) -> F {
+        self.domain
+            .lagrange_coefficients(z)
+            .iter()
+            .zip(self.evaluations.iter())
+            .map(|(l, y)| *l * y)
+            .sum()
+    }
+
+    /// Degree of the interpolant (zero for the zero polynomial).
+    pub fn degree(&self) -> usize {
+        self.to_dense().degree()
+    }
+
+    /// Whether the interpolant has degree strictly below `bound`.
+    pub fn is_degree_below(&self, bound: usize) -> bool {
+        let coefficient = self.domain.ifft(&self.evaluations);
+        coefficient.iter().skip(bound).all(|coeff| coeff.is_zero())
+    }
+
+    fn assert_same_domain(&self, rhs: &Self) {
+        assert_eq!(
+            self.domain, rhs.domain,
+            "Evaluations must be over the same domain"
+        );
+    }
+}
+
+impl<F: FftField> Add for &UnivariatePolyEvals<F> {
+    type Output = UnivariatePolyEvals<F>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.assert_same_domain(rhs);
+        UnivariatePolyEvals::new(
+            self.evaluations
+                .iter()
+                .zip(rhs.evaluations.iter())
+                .map(|(a, b)| *a + b)
+                .collect(),
+            self.domain,
+        )
+    }
+}
+
+impl<F: FftField> Sub for &UnivariatePolyEvals<F> {
+    type Output = UnivariatePolyEvals<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.assert_same_domain(rhs);
+        UnivariatePolyEvals::new(
+            self.evaluations
+                .iter()
+                .zip(rhs.evaluations.iter())
+                .map(|(a, b)| *a - b)
+                .collect(),
+            self.domain,
+        )
+    }
+}
+
+impl<F: FftField> Mul for &UnivariatePolyEvals<F> {
+    type Output = UnivariatePolyEvals<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.assert_same_domain(rhs);
+        UnivariatePolyEvals::new(
+            self.evaluations
+                .iter()
+                .zip(rhs.evaluations.iter())
+                .map(|(a, b)| *a * b)
+                .collect(),
+            self.domain,
+        )
+    }
+}
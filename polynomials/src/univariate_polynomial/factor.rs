@@ -0,0 +1,183 @@
+use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+use ark_ff::{BigInteger, BitIteratorBE, One, PrimeField, Zero};
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+// Cantor-Zassenhaus only needs its splitting polynomials to be unpredictable to the input, not
+// secret, so a fixed seed keeps factorizations reproducible.
+const SPLITTING_SEED: u64 = 0x5eed;
+
+impl<F: PrimeField> UnivariatePolyDense<F> {
+    /// Computes `self^exponent mod modulus`, with `exponent` given as little-endian limbs.
+    pub fn pow_mod(&self, exponent: &[u64], modulus: &Self) -> Self {
+        let base = self % modulus;
+        let mut result = &Self::one() % modulus;
+        for bit in BitIteratorBE::without_leading_zeros(exponent) {
+            result = &(&result * &result) % modulus;
+            if bit {
+                result = &(&result * &base) % modulus;
+            }
+        }
+        result
+    }
+
+    /// The distinct roots of the polynomial in `F`, in ascending order.
+    ///
+    /// Panics on the zero polynomial, which vanishes everywhere.
+    pub fn roots(&self) -> Vec<F> {
+        assert!(!self.is_zero(), "The zero polynomial vanishes everywhere");
+        if self.degree() == 0 {
+            return vec![];
+        }
+
+        // gcd(f, X^q - X) is the product of the distinct linear factors of f.
+        let monic = self.monic();
+        let x = Self::new(vec![F::zero(), F::one()]);
+        let x_q = x.pow_mod(F::MODULUS.as_ref(), &monic);
+        let linear_part = monic.gcd(&(&x_q - &x));
+
+        let mut rng = StdRng::seed_from_u64(SPLITTING_SEED);
+        let mut roots: Vec<F> = linear_part
+            .equal_degree_split(1, &mut rng)
+            .iter()
+            .map(|factor| -factor.coefficient[0])
+            .collect();
+        roots.sort();
+        roots
+    }
+
+    /// Factors the polynomial into monic irreducibles with multiplicities, ordered by degree and
+    /// then coefficients. The leading coefficient of `self` is not included.
+    ///
+    /// Panics on the zero polynomial.
+    pub fn factor(&self) -> Vec<(Self, usize)> {
+        assert!(!self.is_zero(), "The zero polynomial has no factorization");
+
+        let mut rng = StdRng::seed_from_u64(SPLITTING_SEED);
+        let mut factors = vec![];
+        for (squarefree, multiplicity) in self.squarefree_decomposition() {
+            for (block, degree) in squarefree.distinct_degree_factorization() {
+                for irreducible in block.equal_degree_split(degree, &mut rng) {
+                    factors.push((irreducible, multiplicity));
+                }
+            }
+        }
+
+        factors.sort_by(|(a, _), (b, _)| {
+            (a.degree(), &a.coefficient).cmp(&(b.degree(), &b.coefficient))
+        });
+        factors
+    }
+
+    /// Writes the monic associate of `self` as `prod g_i^i` with each `g_i` squarefree and
+    /// pairwise coprime, returning the non-trivial `(g_i, i)`.
+    pub fn squarefree_decomposition(&self) -> Vec<(Self, usize)> {
+        let monic = self.monic();
+        if monic.degree() == 0 {
+            return vec![];
+        }
+
+        let derivative = monic.derivative();
+        if derivative.is_zero() {
+            return monic.pth_root_decomposition();
+        }
+
+        let mut result = vec![];
+        let mut c = monic.gcd(&derivative);
+        let mut w = &monic / &c;
+        let mut multiplicity = 1;
+        while w.degree() > 0 {
+            let y = w.gcd(&c);
+            let factor = &w / &y;
+            if factor.degree() > 0 {
+                result.push((factor, multiplicity));
+            }
+            c = &c / &y;
+            w = y;
+            multiplicity += 1;
+        }
+
+        // What remains only has factors whose multiplicity is a multiple of the characteristic.
+        if c.degree() > 0 {
+            result.extend(c.pth_root_decomposition());
+        }
+        result
+    }
+
+    // A polynomial with zero derivative is g(X^p) = g(X)^p over a prime field.
+    fn pth_root_decomposition(&self) -> Vec<(Self, usize)> {
+        let p = F::MODULUS.as_ref()[0] as usize;
+        let root = Self::new(self.coefficient.iter().step_by(p).copied().collect());
+        root.squarefree_decomposition()
+            .into_iter()
+            .map(|(factor, multiplicity)| (factor, multiplicity * p))
+            .collect()
+    }
+
+    // Splits a monic squarefree polynomial into `(h_d, d)` where `h_d` is the product of all its
+    // irreducible factors of degree `d`.
+    fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let x = Self::new(vec![F::zero(), F::one()]);
+        let mut remaining = self.clone();
+        let mut x_power = x.clone();
+        let mut result = vec![];
+
+        let mut degree = 1;
+        while remaining.degree() >= 2 * degree {
+            // x_power = X^(q^degree) mod remaining
+            x_power = x_power.pow_mod(F::MODULUS.as_ref(), &remaining);
+            let block = remaining.gcd(&(&x_power - &x));
+            if block.degree() > 0 {
+                remaining = &remaining / &block;
+                x_power = &x_power % &remaining;
+                result.push((block, degree));
+            }
+            degree += 1;
+        }
+
+        if remaining.degree() > 0 {
+            let degree = remaining.degree();
+            result.push((remaining, degree));
+        }
+        result
+    }
+
+    // Cantor-Zassenhaus: splits a monic squarefree product of irreducibles of degree `degree`.
+    fn equal_degree_split(&self, degree: usize, rng: &mut StdRng) -> Vec<Self> {
+        assert!(F::MODULUS.is_odd(), "Equal-degree splitting needs an odd characteristic");
+
+        let n = self.degree();
+        if n == 0 {
+            return vec![];
+        }
+        if n == degree {
+            return vec![self.clone()];
+        }
+
+        loop {
+            let a = Self::new((0..n).map(|_| F::rand(rng)).collect());
+            if a.degree() == 0 {
+                continue;
+            }
+
+            let mut split = self.gcd(&a);
+            if split.degree() == 0 {
+                // a^((q^d - 1) / 2) = (a * a^q * ... * a^(q^(d-1)))^((q - 1) / 2)
+                let mut frobenius = &a % self;
+                let mut norm = frobenius.clone();
+                for _ in 1..degree {
+                    frobenius = frobenius.pow_mod(F::MODULUS.as_ref(), self);
+                    norm = &(&norm * &frobenius) % self;
+                }
+                let b = norm.pow_mod(F::MODULUS_MINUS_ONE_DIV_TWO.as_ref(), self);
+                split = self.gcd(&(&b - &Self::one()));
+            }
+
+            if split.degree() > 0 && split.degree() < n {
+                let cofactor = self / &split;
+                let mut factors = split.equal_degree_split(degree, rng);
+                factors.extend(cofactor.equal_degree_split(degree, rng));
+                return factors;
+            }
+        }
+    }
+}
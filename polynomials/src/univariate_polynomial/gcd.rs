@@ -0,0 +1,69 @@
+use crate::error::PolynomialError;
+use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+use ark_ff::{FftField, One, Zero};
+
+impl<F: FftField> UnivariatePolyDense<F> {
+    /// Returns the polynomial scaled to have leading coefficient one (zero stays zero).
+    pub fn monic(&self) -> Self {
+        match self.coefficient.last() {
+            Some(leading) => self.scalar_mul(&leading.inverse().unwrap()),
+            None => self.clone(),
+        }
+    }
+
+    /// Monic greatest common divisor of `self` and `other`; zero only if both are zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder = &a % &b;
+            a = b;
+            b = remainder;
+        }
+        a.monic()
+    }
+
+    /// Extended Euclid: returns `(g, s, t)` with `g = s * self + t * other` and `g` the monic
+    /// gcd of the two polynomials.
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::one(), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::one());
+
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1);
+            r0 = std::mem::replace(&mut r1, remainder);
+
+            let s2 = &s0 - &(&quotient * &s1);
+            s0 = std::mem::replace(&mut s1, s2);
+
+            let t2 = &t0 - &(&quotient * &t1);
+            t0 = std::mem::replace(&mut t1, t2);
+        }
+
+        // Normalise so the gcd is monic, scaling the Bezout coefficients alike.
+        match r0.coefficient.last() {
+            Some(leading) => {
+                let leading_inv = leading.inverse().unwrap();
+                (
+                    r0.scalar_mul(&leading_inv),
+                    s0.scalar_mul(&leading_inv),
+                    t0.scalar_mul(&leading_inv),
+                )
+            }
+            None => (r0, s0, t0),
+        }
+    }
+
+    /// Inverse of `self` in `F[X] / (modulus)`, reduced below the modulus' degree.
+    pub fn inverse_mod(&self, modulus: &Self) -> Result<Self, PolynomialError> {
+        if modulus.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+
+        let (gcd, s, _) = self.xgcd(modulus);
+        if !gcd.is_one() {
+            return Err(PolynomialError::NotInvertible);
+        }
+        Ok(&s % modulus)
+    }
+}
@@ -1,2 +1,10 @@
+pub mod barycentric;
+pub mod display;
+pub mod domain;
+pub mod evaluations;
+pub mod factor;
+pub mod gcd;
+pub mod multipoint;
+pub mod series;
 pub mod univariate;
 mod test;
\ No newline at end of file
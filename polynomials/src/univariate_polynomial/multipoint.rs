@@ -0,0 +1,126 @@
+use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+use ark_ff::{batch_inversion, FftField};
+
+/// Binary tree of products of linear factors `(X - x_i)` over a fixed set of points.
+///
+/// `levels[0]` holds the leaves and the last level holds the single root, the vanishing
+/// polynomial of every point. Node `j` of a level is the product of nodes `2j` and `2j + 1` of
+/// the level below; an unpaired last node is carried up unchanged.
+#[derive(Debug, Clone)]
+pub struct SubproductTree<F: FftField> {
+    pub points: Vec<F>,
+    pub levels: Vec<Vec<UnivariatePolyDense<F>>>,
+}
+
+impl<F: FftField> SubproductTree<F> {
+    pub fn new(points: &[F]) -> Self {
+        assert!(!points.is_empty(), "Subproduct tree needs at least one point");
+
+        let leaves: Vec<UnivariatePolyDense<F>> = points
+            .iter()
+            .map(|x| UnivariatePolyDense::new(vec![-*x, F::one()]))
+            .collect();
+
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        SubproductTree {
+            points: points.to_vec(),
+            levels,
+        }
+    }
+
+    /// The vanishing polynomial `prod (X - x_i)` of the tree's points.
+    pub fn root(&self) -> &UnivariatePolyDense<F> {
+        &self.levels.last().unwrap()[0]
+    }
+
+    /// Evaluates `poly` at every point by reducing it down the tree.
+    pub fn evaluate(&self, poly: &UnivariatePolyDense<F>) -> Vec<F> {
+        let mut remainders = vec![poly % self.root()];
+
+        for level in self.levels.iter().rev().skip(1) {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, node)| &remainders[i / 2] % node)
+                .collect();
+        }
+
+        // Remainders modulo the linear leaves are constants (or the empty zero polynomial).
+        remainders
+            .iter()
+            .map(|remainder| remainder.evaluate(F::zero()))
+            .collect()
+    }
+
+    /// Returns the polynomial of degree below the number of points taking the values `ys`.
+    pub fn interpolate(&self, ys: &[F]) -> UnivariatePolyDense<F> {
+        assert_eq!(
+            ys.len(),
+            self.points.len(),
+            "Number of values must equal the number of points"
+        );
+
+        // Barycentric weights: m'(x_i) = prod_{j != i} (x_i - x_j).
+        let mut weights = self.evaluate(&self.root().derivative());
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "Interpolation points must be distinct"
+        );
+        batch_inversion(&mut weights);
+
+        let mut combined: Vec<UnivariatePolyDense<F>> = ys
+            .iter()
+            .zip(weights.iter())
+            .map(|(y, w)| UnivariatePolyDense::new(vec![*y * w]))
+            .collect();
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            combined = combined
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(values, nodes)| match (values, nodes) {
+                    ([left, right], [left_node, right_node]) => {
+                        &(left * right_node) + &(right * left_node)
+                    }
+                    ([single], [_]) => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        combined.pop().unwrap()
+    }
+}
+
+impl<F: FftField> UnivariatePolyDense<F> {
+    /// Evaluates the polynomial at every point using a subproduct tree.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if points.is_empty() {
+            return vec![];
+        }
+        SubproductTree::new(points).evaluate(self)
+    }
+
+    /// Interpolates through `(xs[i], ys[i])` using a subproduct tree. No points give the zero
+    /// polynomial, as with `interpolate`.
+    pub fn interpolate_fast(xs: &[F], ys: &[F]) -> Self {
+        if xs.is_empty() && ys.is_empty() {
+            return UnivariatePolyDense::new(vec![]);
+        }
+        SubproductTree::new(xs).interpolate(ys)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::error::PolynomialError;
+use crate::univariate_polynomial::univariate::UnivariatePolyDense;
+use ark_ff::{FftField, Zero};
+
+impl<F: FftField> UnivariatePolyDense<F> {
+    /// Truncated power-series inverse: returns `g` of degree below `n` with
+    /// `self * g = 1 mod X^n`.
+    ///
+    /// Uses Newton iteration `g <- g * (2 - self * g)`, doubling the precision each step, so
+    /// the cost is a constant number of multiplications of size `n`.
+    pub fn inverse_series(&self, n: usize) -> Result<Self, PolynomialError> {
+        let constant = self
+            .coefficient
+            .first()
+            .filter(|coeff| !coeff.is_zero())
+            .ok_or(PolynomialError::NotInvertible)?;
+        if n == 0 {
+            return Ok(UnivariatePolyDense::zero());
+        }
+
+        let mut inverse = UnivariatePolyDense::new(vec![constant.inverse().unwrap()]);
+        let mut precision = 1;
+        while precision < n {
+            precision = (2 * precision).min(n);
+
+            let product = (&self.truncate_series(precision) * &inverse).truncate_series(precision);
+            let correction = &UnivariatePolyDense::new(vec![F::from(2u64)]) - &product;
+            inverse = (&inverse * &correction).truncate_series(precision);
+        }
+
+        Ok(inverse)
+    }
+
+    /// Division via reversed polynomials: `rev(q) = rev(a) * rev(b)^-1 mod X^(deg a - deg b + 1)`.
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub(crate) fn fast_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "Division by the zero polynomial");
+        if self.is_zero() || self.degree() < divisor.degree() {
+            return (UnivariatePolyDense::zero(), self.clone());
+        }
+
+        let quotient_len = self.degree() - divisor.degree() + 1;
+        let reversed_inverse = divisor
+            .reverse()
+            .inverse_series(quotient_len)
+            .expect("Reversed divisor has its non-zero leading coefficient as constant term");
+
+        let mut quotient = (&self.reverse() * &reversed_inverse)
+            .truncate_series(quotient_len)
+            .coefficient;
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+        let quotient = UnivariatePolyDense::new(quotient);
+
+        let remainder = self - &(&quotient * divisor);
+        (quotient, remainder)
+    }
+
+    // Reduces modulo X^n.
+    fn truncate_series(&self, n: usize) -> Self {
+        UnivariatePolyDense::new(self.coefficient.iter().take(n).copied().collect())
+    }
+
+    // Coefficients in reverse order, i.e. X^deg * f(1 / X).
+    fn reverse(&self) -> Self {
+        UnivariatePolyDense::new(self.coefficient.iter().rev().copied().collect())
+    }
+}
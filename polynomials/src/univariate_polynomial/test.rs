@@ -1,10 +1,16 @@
 
 #[cfg(test)]
 mod test {
+    use crate::error::PolynomialError;
+    use crate::univariate_polynomial::barycentric::{evaluate_from_points, BarycentricWeights};
+    use crate::univariate_polynomial::domain::EvaluationDomain;
+    use crate::univariate_polynomial::evaluations::UnivariatePolyEvals;
+    use crate::univariate_polynomial::multipoint::SubproductTree;
     use crate::univariate_polynomial::univariate::UnivariatePolyDense;
     use crate::univariate_polynomial::univariate::UnivariatePolySparse;
     use ark_bn254::Fq;
     use ark_bn254::Fr;
+    use ark_ff::{FftField, Field, One, Zero};
 
     fn poly_1() -> UnivariatePolyDense<Fq> {
         UnivariatePolyDense::new(vec![Fq::from(1), Fq::from(2), Fq::from(3)])
@@ -120,4 +126,605 @@ mod test {
         );
         assert_eq!(double.coefficient, vec![(Fr::from(2), 1)]);
     }
-}
\ No newline at end of file
+
+    fn sequence_poly(len: usize, seed: u64) -> UnivariatePolyDense<Fr> {
+        UnivariatePolyDense::new(
+            (0..len as u64)
+                .map(|i| Fr::from(i * i * seed + 7 * i + seed))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_domain_fft_matches_evaluate() {
+        let poly = sequence_poly(6, 3);
+        let domain = EvaluationDomain::<Fr>::new(poly.coefficient.len()).unwrap();
+        assert_eq!(domain.size, 8);
+
+        let evaluations = domain.fft(&poly.coefficient);
+        for (point, value) in domain.elements().iter().zip(evaluations.iter()) {
+            assert_eq!(poly.evaluate(*point), *value);
+        }
+    }
+
+    #[test]
+    fn test_domain_ifft_inverts_fft() {
+        let poly = sequence_poly(16, 5);
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        assert_eq!(domain.ifft(&domain.fft(&poly.coefficient)), poly.coefficient);
+    }
+
+    #[test]
+    fn test_domain_too_large() {
+        assert!(EvaluationDomain::<Fr>::new(1 << 29).is_none());
+    }
+
+    #[test]
+    fn test_ntt_multiply_matches_naive() {
+        let poly_1 = sequence_poly(100, 2);
+        let poly_2 = sequence_poly(77, 9);
+
+        assert_eq!((&poly_1 * &poly_2), poly_1.naive_mul(&poly_2));
+        assert_eq!(poly_1.ntt_mul(&poly_2).unwrap(), poly_1.naive_mul(&poly_2));
+    }
+
+    #[test]
+    fn test_div_rem_dense() {
+        // (x^3 + 2x + 5) = (x^2 + x + 3)(x - 1) + 8
+        let dividend = UnivariatePolyDense::new(vec![Fq::from(5), Fq::from(2), Fq::from(0), Fq::from(1)]);
+        let divisor = UnivariatePolyDense::new(vec![-Fq::from(1), Fq::from(1)]);
+
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert_eq!(quotient.coefficient, vec![Fq::from(3), Fq::from(1), Fq::from(1)]);
+        assert_eq!(remainder.coefficient, vec![Fq::from(8)]);
+
+        assert_eq!(&dividend / &divisor, quotient);
+        assert_eq!(&dividend % &divisor, remainder);
+    }
+
+    #[test]
+    fn test_div_rem_reconstructs_dividend() {
+        let dividend = sequence_poly(40, 3);
+        let divisor = sequence_poly(13, 11);
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+
+        assert!(remainder.degree() < divisor.degree());
+        assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+    }
+
+    #[test]
+    fn test_exact_div_dense() {
+        let factor_1 = sequence_poly(5, 2);
+        let factor_2 = sequence_poly(4, 7);
+        let product = &factor_1 * &factor_2;
+
+        assert_eq!(product.exact_div(&factor_2), Ok(factor_1));
+        assert_eq!(
+            product.exact_div(&sequence_poly(3, 1)),
+            Err(PolynomialError::NonZeroRemainder)
+        );
+        assert_eq!(
+            product.exact_div(&UnivariatePolyDense::new(vec![Fr::from(0)])),
+            Err(PolynomialError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_divide_by_linear_dense() {
+        let poly = sequence_poly(9, 4);
+        let z = Fr::from(12);
+        let (quotient, remainder) = poly.divide_by_linear(z);
+
+        assert_eq!(remainder, poly.evaluate(z));
+        assert_eq!(
+            quotient,
+            poly.div_rem(&UnivariatePolyDense::new(vec![-z, Fr::from(1)])).0
+        );
+    }
+
+    #[test]
+    fn test_sub_and_neg_dense() {
+        assert_eq!(
+            (poly_1() - poly_2()).coefficient,
+            [
+                vec![-Fq::from(2), -Fq::from(2), Fq::from(3)],
+                vec![Fq::from(0); 8],
+                vec![-Fq::from(5)]
+            ]
+            .concat()
+        );
+        assert!((poly_1() - poly_1()).is_zero());
+        assert_eq!(-poly_1() + poly_1(), UnivariatePolyDense::zero() + poly_1() - poly_1());
+    }
+
+    #[test]
+    fn test_assign_operators_dense() {
+        let other = UnivariatePolyDense::new(vec![Fq::from(7), Fq::from(1), Fq::from(4)]);
+        let mut poly = poly_1();
+        poly += &other;
+        assert_eq!(poly.coefficient, vec![Fq::from(8), Fq::from(3), Fq::from(7)]);
+        poly -= other;
+        assert_eq!(poly, poly_1());
+
+        poly *= UnivariatePolyDense::one();
+        poly *= Fq::from(3);
+        assert_eq!(poly, &poly_1() * Fq::from(3));
+        assert_eq!(poly.coefficient, vec![Fq::from(3), Fq::from(6), Fq::from(9)]);
+    }
+
+    #[test]
+    fn test_owned_operators_dense() {
+        let product = poly_1() * poly_2();
+        assert_eq!(product, &poly_1() * &poly_2());
+        assert_eq!(product.clone() / poly_2(), poly_1());
+        assert!((product % &poly_1()).is_zero());
+    }
+
+    #[test]
+    fn test_sub_and_neg_sparse() {
+        let difference = poly_4() - poly_3();
+        assert_eq!(
+            difference.coefficient,
+            vec![
+                (Fr::from(5), 11),
+                (-Fr::from(3), 2),
+                (Fr::from(2), 1),
+                (Fr::from(2), 0)
+            ]
+        );
+        assert!((poly_3() - &poly_3()).is_zero());
+        assert_eq!((-poly_3()).evaluate(Fr::from(2)), -poly_3().evaluate(Fr::from(2)));
+    }
+
+    #[test]
+    fn test_scalar_and_assign_operators_sparse() {
+        let mut poly = poly_3();
+        poly *= Fr::from(2);
+        assert_eq!(
+            poly.coefficient,
+            vec![(Fr::from(6), 2), (Fr::from(4), 1), (Fr::from(2), 0)]
+        );
+
+        poly += poly_4();
+        assert_eq!(poly.evaluate(Fr::from(2)), Fr::from(34) + Fr::from(10251));
+        assert_eq!(
+            (poly_3() * UnivariatePolySparse::one()).evaluate(Fr::from(5)),
+            poly_3().evaluate(Fr::from(5))
+        );
+    }
+
+    #[test]
+    fn test_subproduct_tree_root_vanishes() {
+        let points: Vec<Fr> = (1..=7).map(Fr::from).collect();
+        let tree = SubproductTree::new(&points);
+
+        assert_eq!(tree.root().degree(), 7);
+        assert!(points.iter().all(|x| tree.root().evaluate(*x).is_zero()));
+    }
+
+    #[test]
+    fn test_evaluate_many_dense() {
+        let poly = sequence_poly(60, 5);
+        let points: Vec<Fr> = (0..45u64).map(|i| Fr::from(3 * i + 2)).collect();
+
+        let expected: Vec<Fr> = points.iter().map(|x| poly.evaluate(*x)).collect();
+        assert_eq!(poly.evaluate_many(&points), expected);
+    }
+
+    #[test]
+    fn test_interpolate_fast_matches_interpolate() {
+        let xs: Vec<Fr> = (1..=6).map(Fr::from).collect();
+        let ys: Vec<Fr> = (1..=6).map(|i| Fr::from(i * i * i + 4)).collect();
+
+        assert_eq!(
+            UnivariatePolyDense::interpolate_fast(&xs, &ys),
+            UnivariatePolyDense::interpolate(xs, ys)
+        );
+    }
+
+    #[test]
+    fn test_interpolate_fast_round_trip() {
+        let poly = sequence_poly(100, 13);
+        let xs: Vec<Fr> = (0..100u64).map(|i| Fr::from(i * i + 1)).collect();
+        let ys = poly.evaluate_many(&xs);
+
+        assert_eq!(UnivariatePolyDense::interpolate_fast(&xs, &ys), poly);
+    }
+
+    #[test]
+    fn test_barycentric_evaluation() {
+        let poly = sequence_poly(5, 6);
+        let xs: Vec<Fr> = vec![2, 3, 5, 7, 11].into_iter().map(Fr::from).collect();
+        let ys: Vec<Fr> = xs.iter().map(|x| poly.evaluate(*x)).collect();
+        let weights = BarycentricWeights::new(&xs);
+
+        for z in [0u64, 1, 5, 42] {
+            assert_eq!(
+                evaluate_from_points(&weights, &ys, Fr::from(z)),
+                poly.evaluate(Fr::from(z))
+            );
+        }
+    }
+
+    #[test]
+    fn test_barycentric_consecutive_nodes() {
+        let xs: Vec<Fr> = (0..4u64).map(Fr::from).collect();
+        let consecutive = BarycentricWeights::<Fr>::consecutive(3);
+        assert_eq!(consecutive, BarycentricWeights::new(&xs));
+
+        let poly = sequence_poly(4, 2);
+        let ys: Vec<Fr> = xs.iter().map(|x| poly.evaluate(*x)).collect();
+        assert_eq!(
+            evaluate_from_points(&consecutive, &ys, Fr::from(19)),
+            poly.evaluate(Fr::from(19))
+        );
+    }
+
+    fn coset_domain(size: usize) -> EvaluationDomain<Fr> {
+        EvaluationDomain::new_coset(size, Fr::from(7)).unwrap()
+    }
+
+    #[test]
+    fn test_coset_fft_matches_evaluate() {
+        let poly = sequence_poly(8, 3);
+        let domain = coset_domain(8);
+        let evaluations = domain.fft(&poly.coefficient);
+
+        for (point, value) in domain.elements().iter().zip(evaluations.iter()) {
+            assert_eq!(poly.evaluate(*point), *value);
+        }
+        assert_eq!(domain.ifft(&evaluations), poly.coefficient);
+    }
+
+    #[test]
+    fn test_evals_round_trip() {
+        let poly = sequence_poly(5, 9);
+        for domain in [EvaluationDomain::new(8).unwrap(), coset_domain(8)] {
+            let evals = UnivariatePolyEvals::from_dense(&poly, domain);
+            assert_eq!(evals.to_dense(), poly);
+            assert_eq!(evals.degree(), 4);
+            assert!(evals.is_degree_below(5));
+            assert!(!evals.is_degree_below(4));
+        }
+    }
+
+    #[test]
+    fn test_evals_pointwise_arithmetic() {
+        let poly_1 = sequence_poly(5, 2);
+        let poly_2 = sequence_poly(4, 5);
+        let domain = coset_domain(8);
+        let evals_1 = UnivariatePolyEvals::from_dense(&poly_1, domain);
+        let evals_2 = UnivariatePolyEvals::from_dense(&poly_2, domain);
+
+        assert_eq!((&evals_1 + &evals_2).to_dense(), &poly_1 + &poly_2);
+        assert_eq!((&evals_1 - &evals_2).to_dense(), &poly_1 - &poly_2);
+        assert_eq!((&evals_1 * &evals_2).to_dense(), &poly_1 * &poly_2);
+    }
+
+    #[test]
+    fn test_evals_out_of_domain_evaluation() {
+        let poly = sequence_poly(6, 4);
+        for domain in [EvaluationDomain::new(8).unwrap(), coset_domain(8)] {
+            let evals = UnivariatePolyEvals::from_dense(&poly, domain);
+            assert_eq!(evals.evaluate(Fr::from(1234)), poly.evaluate(Fr::from(1234)));
+            assert_eq!(evals.evaluate(domain.element(3)), evals.evaluations[3]);
+        }
+    }
+
+    #[test]
+    fn test_canonical_form_dense() {
+        let padded = UnivariatePolyDense::new(vec![Fq::from(1), Fq::from(0), Fq::from(0)]);
+        assert_eq!(padded, UnivariatePolyDense::new(vec![Fq::from(1)]));
+        assert_eq!(padded.degree(), 0);
+        assert_eq!(padded.coefficients(), &[Fq::from(1)]);
+
+        let zero = UnivariatePolyDense::new(vec![Fq::from(0); 4]);
+        assert!(zero.is_zero());
+        assert!(zero.coefficients().is_empty());
+        assert_eq!(zero.evaluate(Fq::from(3)), Fq::from(0));
+    }
+
+    #[test]
+    fn test_add_cancelling_leading_terms_dense() {
+        let poly = UnivariatePolyDense::new(vec![Fq::from(1), Fq::from(2), Fq::from(3)]);
+        let cancel = UnivariatePolyDense::new(vec![Fq::from(0), Fq::from(0), -Fq::from(3)]);
+
+        let sum = &poly + &cancel;
+        assert_eq!(sum.degree(), 1);
+        assert_eq!(&cancel + &sum, &sum + &cancel);
+        assert_eq!((&sum + &cancel).degree(), 2);
+    }
+
+    #[test]
+    fn test_canonical_form_sparse() {
+        let poly = UnivariatePolySparse::new(vec![
+            (Fr::from(1), 0),
+            (Fr::from(2), 3),
+            (Fr::from(0), 7),
+            (Fr::from(4), 3),
+            (-Fr::from(1), 0),
+        ]);
+        assert_eq!(poly.terms(), &[(Fr::from(6), 3)]);
+        assert_eq!(poly.degree(), 3);
+
+        // Terms given in ascending order are sorted before evaluation relies on the order.
+        let ascending = UnivariatePolySparse::new(vec![(Fr::from(1), 0), (Fr::from(1), 5)]);
+        assert_eq!(ascending.degree(), 5);
+        assert_eq!(ascending.evaluate(Fr::from(2)), Fr::from(33));
+
+        let product = &UnivariatePolySparse::new(vec![(Fr::from(1), 1), (Fr::from(1), 0)])
+            * &UnivariatePolySparse::new(vec![(Fr::from(1), 1), (-Fr::from(1), 0)]);
+        assert_eq!(product.coefficient, vec![(Fr::from(1), 2), (-Fr::from(1), 0)]);
+    }
+
+    #[test]
+    fn test_interpolate_sparse_keeps_constant_term() {
+        let poly = UnivariatePolySparse::interpolate(
+            vec![Fr::from(0), Fr::from(1)],
+            vec![Fr::from(5), Fr::from(7)],
+        );
+        assert_eq!(poly.coefficient, vec![(Fr::from(2), 1), (Fr::from(5), 0)]);
+    }
+
+    #[test]
+    fn test_dense_sparse_conversion() {
+        let dense = UnivariatePolyDense::from(poly_4());
+        assert_eq!(dense.degree(), 11);
+        assert_eq!(dense.evaluate(Fr::from(2)), poly_4().evaluate(Fr::from(2)));
+        assert_eq!(UnivariatePolySparse::from(&dense), poly_4());
+
+        let zero = UnivariatePolyDense::<Fr>::zero();
+        assert!(UnivariatePolySparse::from(&zero).is_zero());
+        assert_eq!(UnivariatePolyDense::from(UnivariatePolySparse::from(&zero)), zero);
+    }
+
+    fn linear(root: u64) -> UnivariatePolyDense<Fr> {
+        UnivariatePolyDense::new(vec![-Fr::from(root), Fr::from(1)])
+    }
+
+    #[test]
+    fn test_gcd_dense() {
+        let common = &linear(2) * &linear(5);
+        let poly_1 = &common * &linear(7);
+        let poly_2 = &(&common * &linear(9)) * Fr::from(4);
+
+        assert_eq!(poly_1.gcd(&poly_2), common);
+        assert_eq!(poly_1.gcd(&UnivariatePolyDense::zero()), poly_1.monic());
+        assert!(linear(1).gcd(&linear(3)).is_one());
+    }
+
+    #[test]
+    fn test_xgcd_bezout_identity() {
+        let poly_1 = &(&linear(1) * &linear(4)) * &sequence_poly(3, 2);
+        let poly_2 = &linear(4) * &sequence_poly(5, 7);
+        let (gcd, s, t) = poly_1.xgcd(&poly_2);
+
+        assert_eq!(gcd, poly_1.gcd(&poly_2));
+        assert_eq!(&(&s * &poly_1) + &(&t * &poly_2), gcd);
+    }
+
+    #[test]
+    fn test_inverse_mod_dense() {
+        let modulus = &(&linear(1) * &linear(2)) * &linear(3);
+        let poly = sequence_poly(2, 5);
+        let inverse = poly.inverse_mod(&modulus).unwrap();
+
+        assert!(inverse.degree() < modulus.degree());
+        assert!((&(&poly * &inverse) % &modulus).is_one());
+        assert_eq!(
+            linear(2).inverse_mod(&modulus),
+            Err(PolynomialError::NotInvertible)
+        );
+    }
+
+    // The multiplicative generator is a non-residue, so x^2 - g has no roots.
+    fn irreducible_quadratic() -> UnivariatePolyDense<Fr> {
+        UnivariatePolyDense::new(vec![-Fr::GENERATOR, Fr::from(0), Fr::from(1)])
+    }
+
+    #[test]
+    fn test_roots_dense() {
+        let poly = &(&(&linear(3) * &linear(3)) * &linear(11)) * &irreducible_quadratic();
+        let poly = &(&poly * &linear(8)) * Fr::from(5);
+
+        assert_eq!(poly.roots(), vec![Fr::from(3), Fr::from(8), Fr::from(11)]);
+        assert!(irreducible_quadratic().roots().is_empty());
+        assert!(UnivariatePolyDense::new(vec![Fr::from(4)]).roots().is_empty());
+    }
+
+    #[test]
+    fn test_squarefree_decomposition_dense() {
+        let cube = &(&linear(2) * &linear(2)) * &linear(2);
+        let poly = &(&cube * &linear(5)) * &irreducible_quadratic();
+
+        let decomposition = poly.squarefree_decomposition();
+        assert_eq!(
+            decomposition,
+            vec![(&linear(5) * &irreducible_quadratic(), 1), (linear(2), 3)]
+        );
+    }
+
+    #[test]
+    fn test_factor_dense() {
+        let cubic = UnivariatePolyDense::new(vec![
+            -Fr::GENERATOR,
+            Fr::from(0),
+            Fr::from(0),
+            Fr::from(1),
+        ]);
+        let squared_linear = &linear(6) * &linear(6);
+        let poly = &(&(&squared_linear * &irreducible_quadratic()) * &linear(1)) * Fr::from(9);
+        let poly = &poly * &cubic;
+
+        let factors = poly.factor();
+        let recombined: UnivariatePolyDense<Fr> = factors
+            .iter()
+            .flat_map(|(factor, multiplicity)| std::iter::repeat_n(factor.clone(), *multiplicity))
+            .product();
+        assert_eq!(&recombined * Fr::from(9), poly);
+
+        assert_eq!(factors.len(), 4);
+        assert!(factors.contains(&(linear(1), 1)));
+        assert!(factors.contains(&(linear(6), 2)));
+        assert!(factors.contains(&(irreducible_quadratic(), 1)));
+        assert!(factors
+            .iter()
+            .all(|(factor, _)| factor.degree() == 1 || factor.roots().is_empty()));
+    }
+
+    #[test]
+    fn test_derivative_dense() {
+        // d/dx (1 + 2x + 3x^2) = 2 + 6x
+        assert_eq!(
+            poly_1().derivative().coefficient,
+            vec![Fq::from(2), Fq::from(6)]
+        );
+        assert_eq!(poly_1().nth_derivative(2).coefficient, vec![Fq::from(6)]);
+        assert!(poly_1().nth_derivative(3).is_zero());
+        assert_eq!(poly_2().nth_derivative(0), poly_2());
+
+        let poly = sequence_poly(12, 3);
+        assert_eq!(poly.nth_derivative(3), poly.derivative().derivative().derivative());
+    }
+
+    #[test]
+    fn test_compose_dense() {
+        let outer = sequence_poly(4, 2);
+        let inner = sequence_poly(3, 5);
+        let composed = outer.compose(&inner);
+
+        assert_eq!(composed.degree(), 6);
+        for x in [0u64, 1, 7, 100] {
+            let x = Fr::from(x);
+            assert_eq!(composed.evaluate(x), outer.evaluate(inner.evaluate(x)));
+        }
+    }
+
+    #[test]
+    fn test_shift_dense() {
+        let poly = sequence_poly(9, 4);
+        let c = Fr::from(17);
+        let shifted = poly.shift(c);
+
+        assert_eq!(
+            shifted,
+            poly.compose(&UnivariatePolyDense::new(vec![c, Fr::from(1)]))
+        );
+        assert_eq!(shifted.shift(-c), poly);
+    }
+
+    #[test]
+    fn test_inverse_series_dense() {
+        let poly = sequence_poly(20, 3);
+        for n in [1, 2, 7, 33] {
+            let inverse = poly.inverse_series(n).unwrap();
+            assert!(inverse.coefficient.len() <= n);
+
+            let product = &poly * &inverse;
+            assert_eq!(product.coefficient[0], Fr::from(1));
+            assert!(product.coefficient[1..n].iter().all(|coeff| coeff.is_zero()));
+        }
+
+        let no_constant = UnivariatePolyDense::new(vec![Fr::from(0), Fr::from(1)]);
+        assert_eq!(no_constant.inverse_series(4), Err(PolynomialError::NotInvertible));
+    }
+
+    #[test]
+    fn test_fast_division_matches_long_division() {
+        let dividend = sequence_poly(300, 7);
+        let divisor = sequence_poly(90, 2);
+
+        let (quotient, remainder) = dividend.fast_div_rem(&divisor);
+        assert_eq!((quotient.clone(), remainder.clone()), dividend.naive_div_rem(&divisor));
+        assert_eq!(dividend.div_rem(&divisor), (quotient, remainder));
+    }
+
+    #[test]
+    fn test_evaluate_sparse_matches_termwise() {
+        let poly = UnivariatePolySparse::new(vec![
+            (Fr::from(9), 1000),
+            (Fr::from(4), 999),
+            (Fr::from(7), 31),
+            (Fr::from(2), 3),
+        ]);
+        let x = Fr::from(3);
+        let expected: Fr = poly
+            .coefficient
+            .iter()
+            .map(|(coeff, degree)| *coeff * x.pow([*degree as u64]))
+            .sum();
+        assert_eq!(poly.evaluate(x), expected);
+    }
+
+    #[test]
+    fn test_huge_degree_sparse() {
+        let n = 1usize << 40;
+        let vanishing = UnivariatePolySparse::new(vec![(Fr::from(1), n), (-Fr::from(1), 0)]);
+        let plus_one = UnivariatePolySparse::new(vec![(Fr::from(1), n), (Fr::from(1), 0)]);
+
+        let product = &vanishing * &plus_one;
+        assert_eq!(product.coefficient, vec![(Fr::from(1), 2 * n), (-Fr::from(1), 0)]);
+        assert_eq!(product.degree(), 2 * n);
+        assert!((&vanishing - &vanishing).is_zero());
+        assert_eq!((&vanishing + &plus_one).coefficient, vec![(Fr::from(2), n)]);
+        assert_eq!(vanishing.evaluate(Fr::from(1)), Fr::from(0));
+        assert_eq!(vanishing.evaluate(Fr::from(2)), Fr::from(2).pow([n as u64]) - Fr::from(1));
+    }
+
+    #[test]
+    fn test_display_dense() {
+        assert_eq!(poly_1().to_string(), "3x^2 + 2x + 1");
+        assert_eq!(poly_2().to_string(), "5x^11 + 4x + 3");
+        assert_eq!(UnivariatePolyDense::<Fq>::zero().to_string(), "0");
+
+        let negative = UnivariatePolyDense::new(vec![-Fq::from(7), Fq::from(0), -Fq::from(1)]);
+        assert_eq!(negative.to_string(), "-x^2 - 7");
+    }
+
+    #[test]
+    fn test_display_sparse() {
+        assert_eq!(poly_4().to_string(), "5x^11 + 4x + 3");
+        assert_eq!(UnivariatePolySparse::<Fr>::one().to_string(), "1");
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for poly in [poly_1(), poly_2(), -poly_1(), UnivariatePolyDense::zero()] {
+            assert_eq!(poly.to_string().parse::<UnivariatePolyDense<Fq>>(), Ok(poly));
+        }
+        for poly in [poly_3(), poly_4(), -poly_4()] {
+            assert_eq!(poly.to_string().parse::<UnivariatePolySparse<Fr>>(), Ok(poly));
+        }
+
+        let parsed: UnivariatePolyDense<Fr> = " 2 * X^3 - x + x^3 + 7 ".parse().unwrap();
+        assert_eq!(
+            parsed,
+            UnivariatePolyDense::new(vec![Fr::from(7), -Fr::from(1), Fr::from(0), Fr::from(3)])
+        );
+    }
+
+    #[test]
+    fn test_from_str_rejects_malformed() {
+        for input in ["", "3x^", "2y + 1", "x^2 +", "1.5x", "x^-1", "*x", "x^2 - *x", "2**x"] {
+            assert!(matches!(
+                input.parse::<UnivariatePolyDense<Fr>>(),
+                Err(PolynomialError::ParseError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_from_str_rejects_huge_degrees() {
+        for input in ["x^18446744073709551615", "x^1000000000000", "3x^1048577 + 1"] {
+            assert!(matches!(
+                input.parse::<UnivariatePolyDense<Fr>>(),
+                Err(PolynomialError::ParseError(_))
+            ));
+            assert!(matches!(
+                input.parse::<UnivariatePolySparse<Fr>>(),
+                Err(PolynomialError::ParseError(_))
+            ));
+        }
+        assert!("x^1048576".parse::<UnivariatePolySparse<Fr>>().is_ok());
+    }
+}
@@ -1,43 +1,68 @@
-use ark_ff::PrimeField;
+use crate::error::PolynomialError;
+use crate::univariate_polynomial::domain::EvaluationDomain;
+use ark_ff::{FftField, Field, One, Zero};
+use std::collections::BTreeMap;
 use std::iter::{Product, Sum};
-use std::ops::{Add, Mul};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+// Below this many coefficients in the smaller operand, schoolbook multiplication beats the NTT.
+const NTT_MUL_THRESHOLD: usize = 32;
+
+// Below this many coefficients in the divisor or quotient, long division beats Newton iteration.
+const NEWTON_DIV_THRESHOLD: usize = 64;
 
 // ============= STRUCTS =============
-#[derive(Debug, PartialEq, Clone)]
-pub struct UnivariatePolyDense<F: PrimeField> {
-    pub coefficient: Vec<F>,
+/// Coefficients in ascending order of degree. The representation is always canonical: there
+/// are no trailing zero coefficients, so the zero polynomial has no coefficients at all. The
+/// field is private so that this holds; read it through [`UnivariatePolyDense::coefficients`].
+///
+/// Coefficients may come from any `FftField`, prime or extension; large products go through the
+/// NTT over its two-adic subgroup.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnivariatePolyDense<F: FftField> {
+    pub(crate) coefficient: Vec<F>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct UnivariatePolySparse<F: PrimeField> {
-    pub coefficient: Vec<(F, usize)>,
+/// `(coefficient, degree)` terms. The representation is always canonical: terms are sorted by
+/// descending degree, degrees are unique and coefficients are non-zero. Arithmetic and
+/// evaluation rely on this ordering, so the field is private; read it through
+/// [`UnivariatePolySparse::terms`]. Cost depends on the number of terms rather than on the
+/// degree.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnivariatePolySparse<F: Field> {
+    pub(crate) coefficient: Vec<(F, usize)>,
 }
 
 // ============= DENSE IMPLEMENTATIONS =============
 
-impl<F: PrimeField> UnivariatePolyDense<F> {
+impl<F: FftField> UnivariatePolyDense<F> {
     pub fn new(coefficient: Vec<F>) -> Self {
+        let len = strip_trailing_zeros(&coefficient).len();
+        let mut coefficient = coefficient;
+        coefficient.truncate(len);
         UnivariatePolyDense { coefficient }
     }
 
+    /// Coefficients in ascending order of degree, without trailing zeros.
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficient
+    }
+
+    /// Degree of the highest non-zero term; the zero polynomial is given degree 0.
     pub fn degree(&self) -> usize {
-        if self.coefficient.is_empty() {
-            return 0;
-        } else {
-            return self.coefficient.len() - 1;
-        }
+        strip_trailing_zeros(&self.coefficient).len().saturating_sub(1)
     }
 
     pub fn evaluate(&self, x: F) -> F {
         self.coefficient
             .iter()
             .rev()
-            .cloned()
-            .reduce(|acc, curr| acc * x + curr)
-            .unwrap()
+            .fold(F::zero(), |acc, curr| acc * x + curr)
     }
 
-    fn scalar_mul(&self, scalar: &F) -> Self {
+    pub fn scalar_mul(&self, scalar: &F) -> Self {
         UnivariatePolyDense::new(
             self.coefficient
                 .iter()
@@ -68,31 +93,96 @@ impl<F: PrimeField> UnivariatePolyDense<F> {
     }
 }
 
-impl<F: PrimeField> Add for &UnivariatePolyDense<F> {
+impl<F: FftField> UnivariatePolyDense<F> {
+    /// Formal derivative `f'(X)`.
+    pub fn derivative(&self) -> Self {
+        self.nth_derivative(1)
+    }
+
+    /// The `n`-th formal derivative: `a_i X^i` maps to `a_i * i! / (i - n)! * X^(i - n)`.
+    pub fn nth_derivative(&self, n: usize) -> Self {
+        if n == 0 {
+            return self.clone();
+        }
+
+        UnivariatePolyDense::new(
+            self.coefficient
+                .iter()
+                .enumerate()
+                .skip(n)
+                .map(|(i, coeff)| {
+                    let falling_factorial: F = (i - n + 1..=i).map(|k| F::from(k as u64)).product();
+                    falling_factorial * coeff
+                })
+                .collect(),
+        )
+    }
+
+    /// Composition `f(g(X))`, evaluated by Horner's rule over polynomials.
+    pub fn compose(&self, other: &Self) -> Self {
+        self.coefficient
+            .iter()
+            .rev()
+            .fold(UnivariatePolyDense::zero(), |acc, coeff| {
+                &(&acc * other) + &UnivariatePolyDense::new(vec![*coeff])
+            })
+    }
+
+    /// Taylor shift `f(X + c)`.
+    pub fn shift(&self, c: F) -> Self {
+        // In-place Horner: after processing a_i, `result` holds the shift of the tail from a_i up.
+        let mut result = self.coefficient.clone();
+        let n = result.len();
+        for i in (0..n.saturating_sub(1)).rev() {
+            for j in i..n - 1 {
+                let carry = result[j + 1] * c;
+                result[j] += carry;
+            }
+        }
+        UnivariatePolyDense::new(result)
+    }
+}
+
+impl<F: FftField> Add for &UnivariatePolyDense<F> {
     type Output = UnivariatePolyDense<F>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let (mut bigger, smaller) = if self.degree() < rhs.degree() {
+        let (mut bigger, smaller) = if self.coefficient.len() < rhs.coefficient.len() {
             (rhs.clone(), self)
         } else {
             (self.clone(), rhs)
         };
 
-        let _ = bigger
+        bigger
             .coefficient
             .iter_mut()
             .zip(smaller.coefficient.iter())
-            .map(|(b_coeff, s_coeff)| *b_coeff += s_coeff)
-            .collect::<()>();
+            .for_each(|(b_coeff, s_coeff)| *b_coeff += s_coeff);
 
+        // Leading terms may cancel, so renormalise.
         UnivariatePolyDense::new(bigger.coefficient)
     }
 }
 
-impl<F: PrimeField> Mul for &UnivariatePolyDense<F> {
+impl<F: FftField> Mul for &UnivariatePolyDense<F> {
     type Output = UnivariatePolyDense<F>;
 
     fn mul(self, rhs: Self) -> Self::Output {
+        if self.coefficient.len().min(rhs.coefficient.len()) >= NTT_MUL_THRESHOLD {
+            if let Some(product) = self.ntt_mul(rhs) {
+                return product;
+            }
+        }
+        self.naive_mul(rhs)
+    }
+}
+
+impl<F: FftField> UnivariatePolyDense<F> {
+    pub(crate) fn naive_mul(&self, rhs: &Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return UnivariatePolyDense::zero();
+        }
+
         let new_degree = self.degree() + rhs.degree();
         let mut result = vec![F::zero(); new_degree + 1];
         for i in 0..self.coefficient.len() {
@@ -102,11 +192,182 @@ impl<F: PrimeField> Mul for &UnivariatePolyDense<F> {
         }
         UnivariatePolyDense::new(result)
     }
+
+    // Multiplies pointwise over a radix-2 domain; `None` if the field has no such domain.
+    pub(crate) fn ntt_mul(&self, rhs: &Self) -> Option<Self> {
+        let result_len = self.degree() + rhs.degree() + 1;
+        let domain = EvaluationDomain::<F>::new(result_len)?;
+
+        let lhs_evals = domain.fft(&self.coefficient);
+        let rhs_evals = domain.fft(&rhs.coefficient);
+        let product_evals: Vec<F> = lhs_evals
+            .iter()
+            .zip(rhs_evals.iter())
+            .map(|(a, b)| *a * b)
+            .collect();
+
+        let mut result = domain.ifft(&product_evals);
+        result.truncate(result_len);
+        Some(UnivariatePolyDense::new(result))
+    }
+}
+
+impl<F: FftField> UnivariatePolyDense<F> {
+    /// Long division: returns `(q, r)` with `self = q * divisor + r` and `deg(r) < deg(divisor)`.
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_len = strip_trailing_zeros(&divisor.coefficient).len();
+        let dividend_len = strip_trailing_zeros(&self.coefficient).len();
+        if divisor_len >= NEWTON_DIV_THRESHOLD
+            && dividend_len >= divisor_len + NEWTON_DIV_THRESHOLD
+            && EvaluationDomain::<F>::new(dividend_len).is_some()
+        {
+            return self.fast_div_rem(divisor);
+        }
+        self.naive_div_rem(divisor)
+    }
+
+    pub(crate) fn naive_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor = strip_trailing_zeros(&divisor.coefficient);
+        let leading = *divisor.last().expect("Division by the zero polynomial");
+        let leading_inv = leading.inverse().unwrap();
+
+        let mut remainder = strip_trailing_zeros(&self.coefficient).to_vec();
+        if remainder.len() < divisor.len() {
+            return (UnivariatePolyDense::zero(), UnivariatePolyDense::new(remainder));
+        }
+
+        let shift_max = remainder.len() - divisor.len();
+        let mut quotient = vec![F::zero(); shift_max + 1];
+        for shift in (0..=shift_max).rev() {
+            let factor = remainder[shift + divisor.len() - 1] * leading_inv;
+            quotient[shift] = factor;
+            for (i, coeff) in divisor.iter().enumerate() {
+                remainder[shift + i] -= factor * coeff;
+            }
+        }
+
+        remainder.truncate(divisor.len() - 1);
+        (UnivariatePolyDense::new(quotient), UnivariatePolyDense::new(remainder))
+    }
+
+    /// Divides by `divisor`, failing unless it divides `self` exactly.
+    pub fn exact_div(&self, divisor: &Self) -> Result<Self, PolynomialError> {
+        if divisor.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+
+        let (quotient, remainder) = self.div_rem(divisor);
+        if !remainder.is_zero() {
+            return Err(PolynomialError::NonZeroRemainder);
+        }
+        Ok(quotient)
+    }
+
+    /// Synthetic division by `(X - z)`: returns the quotient and the remainder `f(z)`.
+    pub fn divide_by_linear(&self, z: F) -> (Self, F) {
+        if self.coefficient.is_empty() {
+            return (UnivariatePolyDense::zero(), F::zero());
+        }
+
+        let mut quotient = vec![F::zero(); self.coefficient.len() - 1];
+        let mut carry = F::zero();
+        for (i, coeff) in self.coefficient.iter().enumerate().rev() {
+            carry = carry * z + coeff;
+            if i > 0 {
+                quotient[i - 1] = carry;
+            }
+        }
+
+        (UnivariatePolyDense::new(quotient), carry)
+    }
+}
+
+fn strip_trailing_zeros<F: Field>(coefficient: &[F]) -> &[F] {
+    let len = coefficient
+        .iter()
+        .rposition(|coeff| !coeff.is_zero())
+        .map_or(0, |i| i + 1);
+    &coefficient[..len]
+}
+
+impl<F: FftField> Div for &UnivariatePolyDense<F> {
+    type Output = UnivariatePolyDense<F>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<F: FftField> Rem for &UnivariatePolyDense<F> {
+    type Output = UnivariatePolyDense<F>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<F: FftField> Sub for &UnivariatePolyDense<F> {
+    type Output = UnivariatePolyDense<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = self.coefficient.clone();
+        if result.len() < rhs.coefficient.len() {
+            result.resize(rhs.coefficient.len(), F::zero());
+        }
+
+        result
+            .iter_mut()
+            .zip(rhs.coefficient.iter())
+            .for_each(|(r_coeff, s_coeff)| *r_coeff -= s_coeff);
+
+        UnivariatePolyDense::new(result)
+    }
 }
 
-impl<F: PrimeField> Sum for UnivariatePolyDense<F> {
+impl<F: FftField> Neg for &UnivariatePolyDense<F> {
+    type Output = UnivariatePolyDense<F>;
+
+    fn neg(self) -> Self::Output {
+        UnivariatePolyDense::new(self.coefficient.iter().map(|coeff| -*coeff).collect())
+    }
+}
+
+impl<F: FftField> Neg for UnivariatePolyDense<F> {
+    type Output = UnivariatePolyDense<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+forward_binop!(UnivariatePolyDense, FftField, Add, add, AddAssign, add_assign);
+forward_binop!(UnivariatePolyDense, FftField, Sub, sub, SubAssign, sub_assign);
+forward_binop!(UnivariatePolyDense, FftField, Mul, mul, MulAssign, mul_assign);
+forward_binop!(UnivariatePolyDense, FftField, Div, div, DivAssign, div_assign);
+forward_binop!(UnivariatePolyDense, FftField, Rem, rem, RemAssign, rem_assign);
+forward_scalar_mul!(UnivariatePolyDense, FftField);
+
+impl<F: FftField> Zero for UnivariatePolyDense<F> {
+    fn zero() -> Self {
+        UnivariatePolyDense::new(vec![])
+    }
+
+    fn is_zero(&self) -> bool {
+        strip_trailing_zeros(&self.coefficient).is_empty()
+    }
+}
+
+impl<F: FftField> One for UnivariatePolyDense<F> {
+    fn one() -> Self {
+        UnivariatePolyDense::new(vec![F::one()])
+    }
+}
+
+impl<F: FftField> Sum for UnivariatePolyDense<F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut result = UnivariatePolyDense::new(vec![F::zero()]);
+        let mut result = UnivariatePolyDense::zero();
         for poly in iter {
             result = &result + &poly;
         }
@@ -114,7 +375,7 @@ impl<F: PrimeField> Sum for UnivariatePolyDense<F> {
     }
 }
 
-impl<F: PrimeField> Product for UnivariatePolyDense<F> {
+impl<F: FftField> Product for UnivariatePolyDense<F> {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
         let mut result = UnivariatePolyDense::new(vec![F::one()]);
         for poly in iter {
@@ -124,37 +385,76 @@ impl<F: PrimeField> Product for UnivariatePolyDense<F> {
     }
 }
 
+impl<F: FftField> From<UnivariatePolySparse<F>> for UnivariatePolyDense<F> {
+    fn from(poly: UnivariatePolySparse<F>) -> Self {
+        UnivariatePolyDense::from(&poly)
+    }
+}
+
+impl<F: FftField> From<&UnivariatePolySparse<F>> for UnivariatePolyDense<F> {
+    fn from(poly: &UnivariatePolySparse<F>) -> Self {
+        if poly.is_zero() {
+            return UnivariatePolyDense::zero();
+        }
+
+        let mut coefficient = vec![F::zero(); poly.degree() + 1];
+        for (coeff, degree) in &poly.coefficient {
+            coefficient[*degree] += coeff;
+        }
+        UnivariatePolyDense::new(coefficient)
+    }
+}
+
 // ============= SPARSE IMPLEMENTATIONS =============
 
-impl<F: PrimeField> UnivariatePolySparse<F> {
-    pub fn new(coefficient: Vec<(F, usize)>) -> Self {
-        UnivariatePolySparse { coefficient }
+impl<F: Field> UnivariatePolySparse<F> {
+    pub fn new(mut coefficient: Vec<(F, usize)>) -> Self {
+        coefficient.sort_by_key(|(_, d)| std::cmp::Reverse(*d));
+
+        // Merge terms of equal degree, which are now adjacent, and drop the ones that vanish.
+        let mut terms: Vec<(F, usize)> = Vec::with_capacity(coefficient.len());
+        for (coeff, degree) in coefficient {
+            match terms.last_mut() {
+                Some((existing_coeff, d)) if *d == degree => *existing_coeff += coeff,
+                _ => terms.push((coeff, degree)),
+            }
+        }
+        terms.retain(|(coeff, _)| !coeff.is_zero());
+
+        UnivariatePolySparse { coefficient: terms }
+    }
+
+    /// `(coefficient, degree)` terms in strictly descending order of degree, all non-zero.
+    pub fn terms(&self) -> &[(F, usize)] {
+        &self.coefficient
     }
 
+    /// Degree of the highest non-zero term; the zero polynomial is given degree 0.
     pub fn degree(&self) -> usize {
-        self.coefficient.first().map(|(_, d)| *d).unwrap_or(0)
+        self.coefficient.first().map_or(0, |(_, d)| *d)
     }
 
     pub fn evaluate(&self, x: F) -> F {
-        if self.coefficient.is_empty() {
-            return F::zero();
+        // Sparse Horner: walking down the degrees, only the gap to the next term is
+        // exponentiated, so the total cost is logarithmic in the degree per term.
+        let mut acc = F::zero();
+        let mut terms = self.coefficient.iter().peekable();
+        while let Some((coeff, degree)) = terms.next() {
+            acc += coeff;
+            let next_degree = terms.peek().map_or(0, |(_, d)| *d);
+            acc *= x.pow([(degree - next_degree) as u64]);
         }
-
-        self.coefficient
-            .iter()
-            .fold(F::zero(), |acc, (coeff, power)| {
-                acc + *coeff * x.pow(&[*power as u64])
-            })
+        acc
     }
 
-    fn scalar_mul(&self, scalar: &F) -> Self {
-            UnivariatePolySparse::new(
-                self.coefficient
-                    .iter()
-                    .map(|(coeff, degree)| (*coeff * *scalar, *degree))
-                    .collect::<Vec<(F, usize)>>()
-            )
-        }
+    pub fn scalar_mul(&self, scalar: &F) -> Self {
+        UnivariatePolySparse::new(
+            self.coefficient
+                .iter()
+                .map(|(coeff, degree)| (*coeff * *scalar, *degree))
+                .collect::<Vec<(F, usize)>>()
+        )
+    }
 
     fn basis(x: &F, interpolating_set: &[F]) -> Self {
         // numerator
@@ -171,60 +471,120 @@ impl<F: PrimeField> UnivariatePolySparse<F> {
     }
 
     pub fn interpolate(xs: Vec<F>, ys: Vec<F>) -> Self {
-        let sum = xs.iter()
+        xs.iter()
             .zip(ys.iter())
             .map(|(x, y)| Self::basis(x, &xs).scalar_mul(y))
-            .sum::<Self>();
-        
-        UnivariatePolySparse::new(
-            sum.coefficient
-                .into_iter()
-                .filter(|(coeff, deg)| *coeff != F::zero() && *deg != 0)
-                .collect()
-        )
+            .sum()
     }
 }
 
-impl<F: PrimeField> Add for &UnivariatePolySparse<F> {
+impl<F: Field> Add for &UnivariatePolySparse<F> {
     type Output = UnivariatePolySparse<F>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let mut result = self.coefficient.clone();
-        for (coeff, degree) in &rhs.coefficient {
-            match result.iter_mut().find(|(_, d)| *d == *degree) {
-                Some((existing_coeff, _)) => *existing_coeff += coeff,
-                None => result.push((*coeff, *degree)),
+        // Both term lists are sorted by descending degree, so a single merge pass suffices.
+        let mut result = Vec::with_capacity(self.coefficient.len() + rhs.coefficient.len());
+        let mut lhs_terms = self.coefficient.iter().peekable();
+        let mut rhs_terms = rhs.coefficient.iter().peekable();
+
+        loop {
+            let term = match (lhs_terms.peek(), rhs_terms.peek()) {
+                (Some((l_coeff, l_deg)), Some((r_coeff, r_deg))) if l_deg == r_deg => {
+                    let term = (*l_coeff + r_coeff, *l_deg);
+                    lhs_terms.next();
+                    rhs_terms.next();
+                    term
+                }
+                (Some((_, l_deg)), Some((_, r_deg))) if l_deg > r_deg => *lhs_terms.next().unwrap(),
+                (_, Some(_)) => *rhs_terms.next().unwrap(),
+                (Some(_), None) => *lhs_terms.next().unwrap(),
+                (None, None) => break,
+            };
+
+            if !term.0.is_zero() {
+                result.push(term);
             }
         }
 
-        result.sort_by(|a, b| b.1.cmp(&a.1));
-        UnivariatePolySparse::new(result)
+        UnivariatePolySparse { coefficient: result }
     }
 }
 
-impl<F: PrimeField> Mul for &UnivariatePolySparse<F> {
+impl<F: Field> Mul for &UnivariatePolySparse<F> {
     type Output = UnivariatePolySparse<F>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let mut result = vec![];
-        for i in 0..self.coefficient.len() {
-            for j in 0..rhs.coefficient.len() {
-                let coeff = self.coefficient[i].0 * rhs.coefficient[j].0;
-                let degree = self.coefficient[i].1 + rhs.coefficient[j].1;
-                if let Some((existing_coeff, _)) = result.iter_mut().find(|(_, d)| *d == degree) {
-                    *existing_coeff += coeff;
-                } else {
-                    result.push((coeff, degree));
-                }
+        let mut products: BTreeMap<usize, F> = BTreeMap::new();
+        for (l_coeff, l_deg) in &self.coefficient {
+            for (r_coeff, r_deg) in &rhs.coefficient {
+                *products.entry(l_deg + r_deg).or_insert_with(F::zero) += *l_coeff * r_coeff;
             }
         }
-        UnivariatePolySparse::new(result)
+
+        UnivariatePolySparse {
+            coefficient: products
+                .into_iter()
+                .rev()
+                .filter(|(_, coeff)| !coeff.is_zero())
+                .map(|(degree, coeff)| (coeff, degree))
+                .collect(),
+        }
+    }
+}
+
+impl<F: Field> Sub for &UnivariatePolySparse<F> {
+    type Output = UnivariatePolySparse<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl<F: Field> Neg for &UnivariatePolySparse<F> {
+    type Output = UnivariatePolySparse<F>;
+
+    fn neg(self) -> Self::Output {
+        UnivariatePolySparse::new(
+            self.coefficient
+                .iter()
+                .map(|(coeff, degree)| (-*coeff, *degree))
+                .collect(),
+        )
     }
 }
 
-impl<F: PrimeField> Sum for UnivariatePolySparse<F> {
+impl<F: Field> Neg for UnivariatePolySparse<F> {
+    type Output = UnivariatePolySparse<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+forward_binop!(UnivariatePolySparse, Field, Add, add, AddAssign, add_assign);
+forward_binop!(UnivariatePolySparse, Field, Sub, sub, SubAssign, sub_assign);
+forward_binop!(UnivariatePolySparse, Field, Mul, mul, MulAssign, mul_assign);
+forward_scalar_mul!(UnivariatePolySparse, Field);
+
+impl<F: Field> Zero for UnivariatePolySparse<F> {
+    fn zero() -> Self {
+        UnivariatePolySparse::new(vec![])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficient.iter().all(|(coeff, _)| coeff.is_zero())
+    }
+}
+
+impl<F: Field> One for UnivariatePolySparse<F> {
+    fn one() -> Self {
+        UnivariatePolySparse::new(vec![(F::one(), 0)])
+    }
+}
+
+impl<F: Field> Sum for UnivariatePolySparse<F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut result = UnivariatePolySparse::new(vec![(F::zero(), 0)]);
+        let mut result = UnivariatePolySparse::zero();
         for poly in iter {
             result = &result + &poly;
         }
@@ -232,7 +592,7 @@ impl<F: PrimeField> Sum for UnivariatePolySparse<F> {
     }
 }
 
-impl<F: PrimeField> Product for UnivariatePolySparse<F> {
+impl<F: Field> Product for UnivariatePolySparse<F> {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
         let mut result = UnivariatePolySparse::new(vec![(F::one(), 0)]);
         for poly in iter {
@@ -241,3 +601,21 @@ impl<F: PrimeField> Product for UnivariatePolySparse<F> {
         result
     }
 }
+
+impl<F: FftField> From<UnivariatePolyDense<F>> for UnivariatePolySparse<F> {
+    fn from(poly: UnivariatePolyDense<F>) -> Self {
+        UnivariatePolySparse::from(&poly)
+    }
+}
+
+impl<F: FftField> From<&UnivariatePolyDense<F>> for UnivariatePolySparse<F> {
+    fn from(poly: &UnivariatePolyDense<F>) -> Self {
+        UnivariatePolySparse::new(
+            poly.coefficient
+                .iter()
+                .enumerate()
+                .map(|(degree, coeff)| (*coeff, degree))
+                .collect(),
+        )
+    }
+}
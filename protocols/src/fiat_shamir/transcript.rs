@@ -1,14 +1,14 @@
-use ark_ff::PrimeField;
+use ark_ff::{Field, PrimeField};
 use sha3::{Digest, Keccak256};
 use std::marker::PhantomData;
 
 #[derive(Default)]
-pub struct Transcript<K: HashFunctionTrait, F: PrimeField> {
+pub struct Transcript<K: HashFunctionTrait, F: Field> {
     hash_function: K,
     _field: PhantomData<F>,
 }
 
-impl<K: HashFunctionTrait, F: PrimeField> Transcript<K, F> {
+impl<K: HashFunctionTrait, F: Field> Transcript<K, F> {
     pub fn new(hash_function: K) -> Self {
         Self {
             hash_function,
@@ -20,10 +20,17 @@ impl<K: HashFunctionTrait, F: PrimeField> Transcript<K, F> {
         self.hash_function.append(data);
     }
 
+    /// Squeezes one base-field element per coordinate, so over a prime field this is a single
+    /// hash and over an extension every coordinate is independently random.
     pub fn squeeze(&mut self) -> F {
-        let hash_output = self.hash_function.generate_hash();
-        self.absorb(&hash_output);
-        F::from_be_bytes_mod_order(&hash_output)
+        let coordinates: Vec<F::BasePrimeField> = (0..F::extension_degree())
+            .map(|_| {
+                let hash_output = self.hash_function.generate_hash();
+                self.absorb(&hash_output);
+                F::BasePrimeField::from_be_bytes_mod_order(&hash_output)
+            })
+            .collect();
+        F::from_base_prime_field_elems(coordinates).unwrap()
     }
 
     pub fn squeeze_iterator(&mut self, n: usize) -> Vec<F> {
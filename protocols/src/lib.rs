@@ -1,4 +1,7 @@
+#![allow(clippy::module_inception)]
+
 pub mod fiat_shamir;
 pub mod goldwasser_kalai_rothblum;
+pub mod reed_solomon;
 pub mod shamir_secret_sharing;
 pub mod sumcheck;
@@ -0,0 +1,2 @@
+pub mod reed_solomon;
+mod test;
@@ -0,0 +1,183 @@
+use ark_ff::FftField;
+use polynomials::univariate_polynomial::domain::EvaluationDomain;
+use polynomials::univariate_polynomial::univariate::UnivariatePolyDense;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EncodingError {
+    /// The message has more than `message_len` symbols, or its polynomial too high a degree.
+    MessageTooLong { max: usize, actual: usize },
+    /// Low-degree extension needs exactly one evaluation per point of the message domain.
+    WrongLength { expected: usize, actual: usize },
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingError::MessageTooLong { max, actual } => {
+                write!(f, "Message has {} symbols but at most {} fit", actual, max)
+            }
+            EncodingError::WrongLength { expected, actual } => {
+                write!(f, "Expected {} evaluations but received {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodingError {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DecodingError {
+    /// The received word does not have one slot per codeword symbol.
+    WrongLength { expected: usize, actual: usize },
+    /// Fewer than `message_len` symbols survived.
+    TooManyErasures { received: usize, needed: usize },
+    /// The surviving symbols do not all lie on one polynomial of degree below `message_len`.
+    InconsistentSymbols,
+}
+
+impl fmt::Display for DecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodingError::WrongLength { expected, actual } => {
+                write!(f, "Expected {} symbols but received {}", expected, actual)
+            }
+            DecodingError::TooManyErasures { received, needed } => {
+                write!(f, "Only {} symbols survived but {} are needed", received, needed)
+            }
+            DecodingError::InconsistentSymbols => {
+                write!(f, "Received symbols are not a codeword")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodingError {}
+
+/// A Reed-Solomon code of rate `1 / blowup_factor`: a message of `message_len` field elements
+/// is read as the coefficients of a polynomial of degree below `message_len`, and its codeword
+/// is the polynomial's evaluations over a coset of `message_len * blowup_factor` points.
+///
+/// Both `message_len` and `blowup_factor` are powers of two so that encoding, low-degree
+/// extension and decoding all run on radix-2 domains.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReedSolomonCode<F: FftField> {
+    pub message_len: usize,
+    pub blowup_factor: usize,
+    /// The subgroup of size `message_len` that low-degree extension starts from.
+    pub message_domain: EvaluationDomain<F>,
+    /// The coset the codeword is evaluated over.
+    pub codeword_domain: EvaluationDomain<F>,
+}
+
+impl<F: FftField> ReedSolomonCode<F> {
+    /// Evaluates over the coset `F::GENERATOR * <w>`, which is disjoint from every subgroup and
+    /// so from the message domain. Returns `None` if the field has no subgroup large enough.
+    pub fn new(message_len: usize, blowup_factor: usize) -> Option<Self> {
+        Self::new_with_offset(message_len, blowup_factor, F::GENERATOR)
+    }
+
+    /// Like `new`, but over the coset `offset * <w>`; an offset of one gives the plain subgroup.
+    /// Also returns `None` unless `message_len` and `blowup_factor` are powers of two and the
+    /// offset is non-zero.
+    pub fn new_with_offset(message_len: usize, blowup_factor: usize, offset: F) -> Option<Self> {
+        if !message_len.is_power_of_two() || !blowup_factor.is_power_of_two() || offset.is_zero()
+        {
+            return None;
+        }
+
+        Some(ReedSolomonCode {
+            message_len,
+            blowup_factor,
+            message_domain: EvaluationDomain::new(message_len)?,
+            codeword_domain: EvaluationDomain::new_coset(
+                message_len.checked_mul(blowup_factor)?,
+                offset,
+            )?,
+        })
+    }
+
+    pub fn codeword_len(&self) -> usize {
+        self.codeword_domain.size
+    }
+
+    /// The ratio `message_len / codeword_len`.
+    pub fn rate(&self) -> F {
+        F::from(self.message_len as u64) / F::from(self.codeword_len() as u64)
+    }
+
+    /// Encodes a message of at most `message_len` coefficients; shorter ones are zero padded.
+    pub fn encode(&self, message: &[F]) -> Result<Vec<F>, EncodingError> {
+        if message.len() > self.message_len {
+            return Err(EncodingError::MessageTooLong {
+                max: self.message_len,
+                actual: message.len(),
+            });
+        }
+        Ok(self.codeword_domain.fft(message))
+    }
+
+    /// Encodes the polynomial directly; its degree must be below `message_len`.
+    pub fn encode_polynomial(
+        &self,
+        poly: &UnivariatePolyDense<F>,
+    ) -> Result<Vec<F>, EncodingError> {
+        self.encode(poly.coefficients())
+    }
+
+    /// Given the evaluations of a polynomial over the message domain, returns its evaluations over
+    /// the codeword domain.
+    pub fn low_degree_extend(&self, evaluations: &[F]) -> Result<Vec<F>, EncodingError> {
+        if evaluations.len() != self.message_len {
+            return Err(EncodingError::WrongLength {
+                expected: self.message_len,
+                actual: evaluations.len(),
+            });
+        }
+        self.encode(&self.message_domain.ifft(evaluations))
+    }
+
+    /// Recovers the message from a codeword with erasures marked as `None`. Any `message_len`
+    /// surviving symbols determine the message; the rest are checked against it.
+    pub fn decode_erasures(&self, received: &[Option<F>]) -> Result<Vec<F>, DecodingError> {
+        if received.len() != self.codeword_len() {
+            return Err(DecodingError::WrongLength {
+                expected: self.codeword_len(),
+                actual: received.len(),
+            });
+        }
+
+        let (xs, ys): (Vec<F>, Vec<F>) = self
+            .codeword_domain
+            .elements()
+            .into_iter()
+            .zip(received)
+            .filter_map(|(x, symbol)| symbol.map(|y| (x, y)))
+            .unzip();
+        if xs.len() < self.message_len {
+            return Err(DecodingError::TooManyErasures {
+                received: xs.len(),
+                needed: self.message_len,
+            });
+        }
+
+        let poly = UnivariatePolyDense::interpolate_fast(
+            &xs[..self.message_len],
+            &ys[..self.message_len],
+        );
+        // Interpolating `message_len` points leaves the degree below `message_len`.
+        let consistent = self
+            .codeword_domain
+            .fft(poly.coefficients())
+            .iter()
+            .zip(received)
+            .all(|(expected, symbol)| symbol.is_none_or(|y| y == *expected));
+        if !consistent {
+            return Err(DecodingError::InconsistentSymbols);
+        }
+
+        let mut message = poly.coefficients().to_vec();
+        message.resize(self.message_len, F::zero());
+        Ok(message)
+    }
+}
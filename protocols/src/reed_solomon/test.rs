@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod test {
+    use crate::reed_solomon::reed_solomon::{DecodingError, EncodingError, ReedSolomonCode};
+    use ark_bn254::Fr;
+    use ark_ff::{One, UniformRand};
+    use polynomials::univariate_polynomial::univariate::UnivariatePolyDense;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn message(len: usize) -> Vec<Fr> {
+        (1..=len as u64).map(|i| Fr::from(i * i + 7)).collect()
+    }
+
+    #[test]
+    fn test_encode_evaluates_message_polynomial() {
+        let code = ReedSolomonCode::<Fr>::new(8, 4).unwrap();
+        assert_eq!(code.codeword_len(), 32);
+        assert_eq!(code.rate() * Fr::from(4), Fr::one());
+
+        let poly = UnivariatePolyDense::new(message(8));
+        let codeword = code.encode(&message(8)).unwrap();
+        for (i, symbol) in codeword.iter().enumerate() {
+            assert_eq!(*symbol, poly.evaluate(code.codeword_domain.element(i)));
+        }
+        assert_eq!(code.encode_polynomial(&poly), Ok(codeword));
+    }
+
+    #[test]
+    fn test_low_degree_extend_agrees_with_encoding() {
+        let code = ReedSolomonCode::<Fr>::new(4, 8).unwrap();
+        let poly = UnivariatePolyDense::new(message(4));
+        let trace: Vec<Fr> = code
+            .message_domain
+            .elements()
+            .into_iter()
+            .map(|x| poly.evaluate(x))
+            .collect();
+
+        assert_eq!(code.low_degree_extend(&trace), code.encode(&message(4)));
+    }
+
+    #[test]
+    fn test_decode_from_any_k_symbols() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let code = ReedSolomonCode::<Fr>::new(4, 4).unwrap();
+        let message: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+        let codeword = code.encode(&message).unwrap();
+
+        // Keep only every fourth symbol, starting at an odd position.
+        let received: Vec<Option<Fr>> = codeword
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| (i % 4 == 3).then_some(*symbol))
+            .collect();
+        assert_eq!(code.decode_erasures(&received), Ok(message));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let code = ReedSolomonCode::<Fr>::new_with_offset(2, 2, Fr::one()).unwrap();
+        let codeword = code.encode(&message(2)).unwrap();
+
+        assert_eq!(
+            code.decode_erasures(&[Some(codeword[0])]),
+            Err(DecodingError::WrongLength {
+                expected: 4,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            code.decode_erasures(&[None, Some(codeword[1]), None, None]),
+            Err(DecodingError::TooManyErasures {
+                received: 1,
+                needed: 2
+            })
+        );
+
+        let mut corrupted: Vec<Option<Fr>> = codeword.iter().copied().map(Some).collect();
+        corrupted[3] = Some(codeword[3] + Fr::one());
+        assert_eq!(
+            code.decode_erasures(&corrupted),
+            Err(DecodingError::InconsistentSymbols)
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(ReedSolomonCode::<Fr>::new(6, 4).is_none());
+        assert!(ReedSolomonCode::<Fr>::new(8, 3).is_none());
+        assert!(ReedSolomonCode::<Fr>::new(0, 4).is_none());
+        assert!(ReedSolomonCode::<Fr>::new(8, 0).is_none());
+        assert!(ReedSolomonCode::<Fr>::new_with_offset(8, 4, Fr::from(0)).is_none());
+        // Larger than the two-adic subgroup of the field.
+        assert!(ReedSolomonCode::<Fr>::new(1 << 20, 1 << 10).is_none());
+    }
+
+    #[test]
+    fn test_encoding_errors() {
+        let code = ReedSolomonCode::<Fr>::new(4, 2).unwrap();
+        assert_eq!(
+            code.encode(&message(5)),
+            Err(EncodingError::MessageTooLong { max: 4, actual: 5 })
+        );
+        assert_eq!(
+            code.encode_polynomial(&UnivariatePolyDense::new(message(6))),
+            Err(EncodingError::MessageTooLong { max: 4, actual: 6 })
+        );
+        assert_eq!(
+            code.low_degree_extend(&message(3)),
+            Err(EncodingError::WrongLength {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+}
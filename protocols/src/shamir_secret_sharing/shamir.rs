@@ -1,69 +1,62 @@
-use ark_ff::PrimeField;
+use ark_ff::FftField;
+use polynomials::univariate_polynomial::barycentric::{evaluate_from_points, BarycentricWeights};
 use polynomials::univariate_polynomial::univariate::UnivariatePolyDense;
 use rand::rngs::OsRng;
+use rand::RngCore;
 // use univariate_polynomials::{self, UnivariatePolyDense};
 
 // const PRIME: F = 65537;
 
 // Define a struct for shares
 #[derive(Debug, Clone)]
-pub(crate) struct Share<F: PrimeField> {
+pub(crate) struct Share<F: FftField> {
     x: F,
     y: F,
 }
 
 #[allow(dead_code)]
-impl<F: PrimeField> Share<F> {
+impl<F: FftField> Share<F> {
     // Generate a random secret
-    pub(crate) fn create_secret() -> F {
-        let mut rng = OsRng;
+    pub(crate) fn create_secret<R: RngCore>(rng: &mut R) -> F {
         // Use F::rand to generate a random field element
-        let secret = F::rand(&mut rng);
-
-        secret
+        F::rand(rng)
     }
 
-    // Generate polynomial coefficients
-    fn generate_coefficients(secret: F, threshold: usize) -> UnivariatePolyDense<F> {
-        let mut rng = OsRng;
-
-        // Initialize polynomial with the secret
-        let mut poly = UnivariatePolyDense::new(vec![secret]);
-
-        // Generate k-1 random coefficients
-        for _ in 1..threshold {
-            // Generate a random field element
-            let coeff = F::rand(&mut rng);
-            // Add coeff to polynomial
-            poly.coefficient.push(coeff);
-        }
-
-        poly
+    // Generate a random polynomial of degree threshold - 1 hiding the secret at zero
+    fn generate_coefficients<R: RngCore>(
+        secret: F,
+        threshold: usize,
+        rng: &mut R,
+    ) -> UnivariatePolyDense<F> {
+        UnivariatePolyDense::rand_with_constant(threshold - 1, secret, rng)
     }
 
     // Generate shares by evaluating the polynomial at distinct points
-    pub(crate) fn split_secret(secret: F, threshold: usize, num_shares: usize) -> Vec<Share<F>> {
+    pub(crate) fn split_secret<R: RngCore>(
+        secret: F,
+        threshold: usize,
+        num_shares: usize,
+        rng: &mut R,
+    ) -> Vec<Share<F>> {
+        assert!(threshold >= 1, "Threshold must be at least one");
         assert!(
             threshold <= num_shares,
             "Threshold must be less than or equal to total shares"
         );
 
-        let polynomial = Self::generate_coefficients(secret, threshold);
-        // let mut shares = vec![secret];
-        let mut shares = Vec::new();
-        // shares.push(Share{ x: F::zero(), y: secret });
+        let polynomial = Self::generate_coefficients(secret, threshold, rng);
 
         // Create shares of x = degree and y = coefficient @ x
-        for i in 1..=num_shares {
-            let x = F::from(i as u64);
-            let y = polynomial.evaluate(x);
-            shares.push(Share { x, y });
-        }
+        let xs: Vec<F> = (1..=num_shares).map(|i| F::from(i as u64)).collect();
+        let ys = polynomial.evaluate_many(&xs);
 
-        shares
+        xs.into_iter()
+            .zip(ys)
+            .map(|(x, y)| Share { x, y })
+            .collect()
     }
 
-    // Reconstruct the secret using Lagrange interpolation
+    // Reconstruct the secret by evaluating the interpolant at zero in barycentric form
     pub(crate) fn reconstruct_secret(shares: &[Share<F>]) -> F {
         // assert!(shares.len >= threshold, "Shares and threshold are of unequal length!");
 
@@ -73,26 +66,25 @@ impl<F: PrimeField> Share<F> {
         let xs: Vec<F> = shares.iter().map(|p| p.x).collect();
         let ys: Vec<F> = shares.iter().map(|p| p.y).collect();
 
-        // Interpolate the points to get the polynomial
-        let poly = UnivariatePolyDense::interpolate(xs, ys);
-
-        poly.evaluate(F::from(password))
+        let weights = BarycentricWeights::new(&xs);
+        evaluate_from_points(&weights, &ys, password)
     }
 }
 
+#[allow(dead_code)]
 fn main() {
     // Define the field type (e.g., ark_bn254::Fq)
     type F = ark_bn254::Fq;
 
     // Generate a secret
-    let secret: F = Share::create_secret();
+    let secret: F = Share::create_secret(&mut OsRng);
     println!("\nGenerated Secret: {}", secret);
 
     let threshold: usize = 3;
     let num_shares: usize = 5;
 
     // Generate shares
-    let shares: Vec<Share<F>> = Share::split_secret(secret, threshold, num_shares);
+    let shares: Vec<Share<F>> = Share::split_secret(secret, threshold, num_shares, &mut OsRng);
 
     // Print shares
     for (i, share) in shares.iter().enumerate() {
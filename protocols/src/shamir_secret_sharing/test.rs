@@ -2,18 +2,21 @@
 mod tests {
     use crate::shamir_secret_sharing::shamir::Share;
     use ark_bn254::Fq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn test_secret_sharing() {
         type F = Fq;
+        let mut rng = StdRng::seed_from_u64(0);
 
         // Generate a secret
-        let secret: F = Share::create_secret();
+        let secret: F = Share::create_secret(&mut rng);
 
         // Generate shares and polynomial coefficients
         let threshold = 3;
         let num_shares = 5;
-        let shares = Share::split_secret(secret, threshold, num_shares);
+        let shares = Share::split_secret(secret, threshold, num_shares, &mut rng);
 
         // Reconstruct the secret
         let reconstructed_secret = Share::reconstruct_secret(&shares[..threshold]);
@@ -21,4 +24,14 @@ mod tests {
         // Validate that the reconstructed secret matches the original
         assert_eq!(secret, reconstructed_secret);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_too_few_shares_hide_the_secret() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let secret = Fq::from(1234);
+        let shares = Share::split_secret(secret, 3, 5, &mut rng);
+
+        assert_ne!(Share::reconstruct_secret(&shares[..2]), secret);
+        assert_eq!(Share::reconstruct_secret(&shares[2..]), secret);
+    }
+}
@@ -1,11 +1,13 @@
 use crate::fiat_shamir::transcript::Transcript;
-use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_ff::{BigInteger, Field, PrimeField};
 use polynomials::multilinear_polynomial::multilinear::MultilinearPoly;
+use polynomials::traits::MultilinearExtension;
+use polynomials::univariate_polynomial::barycentric::{evaluate_from_points, BarycentricWeights};
 use sha3::{Digest, Keccak256};
 
 /// A proof generated by the sum-check protocol with two evaluation points per round.
 #[derive(Clone, Debug)]
-pub struct Proof<F: PrimeField> {
+pub struct Proof<F: Field> {
     pub claimed_sum: F,
     pub round_polys: Vec<[F; 2]>,
 }
@@ -13,49 +15,69 @@ pub struct Proof<F: PrimeField> {
 /// A proof generated by a modified sum-check protocol (e.g. for a GKR‐style protocol)
 /// that uses three evaluation points per round.
 #[derive(Clone, Debug)]
-pub struct PartialProof<F: PrimeField> {
+pub struct PartialProof<F: Field> {
     pub claimed_sum: F,
     pub round_polys: Vec<[F; 3]>,
 }
 
-/// Helper: convert a slice of field elements into a vector of bytes (big-endian).
-fn absorb_bytes<F: PrimeField, I: IntoIterator<Item = F>>(elements: I) -> Vec<u8> {
+/// Helper: convert a slice of field elements into a vector of bytes (big-endian). Extension
+/// field elements are written coordinate by coordinate.
+fn absorb_bytes<F: Field, I: IntoIterator<Item = F>>(elements: I) -> Vec<u8> {
     elements
         .into_iter()
-        .flat_map(|f| f.into_bigint().to_bytes_be())
+        .flat_map(|f| {
+            f.to_base_prime_field_elements()
+                .flat_map(|coordinate| coordinate.into_bigint().to_bytes_be())
+                .collect::<Vec<u8>>()
+        })
         .collect()
 }
 
-
-fn to_field<F: PrimeField>(input: Vec<u64>) -> Vec<F> {
-    input.iter().map(|v| F::from(*v)).collect()
+/// Helper: `MultilinearPoly::to_extension` for any multilinear extension, via its table.
+fn to_extension<F: PrimeField, E: Field<BasePrimeField = F>, P: MultilinearExtension<F>>(
+    poly: &P,
+) -> MultilinearPoly<E> {
+    MultilinearPoly::new(poly.num_vars(), poly.evaluations().to_vec()).to_extension()
 }
 
 /// Runs the sum-check protocol prover. It takes as input a multilinear polynomial `poly` and
 /// a claimed sum (the “public” sum) and returns a proof that consists of a sequence of round
 /// polynomials. (Each round polynomial has two evaluations.)
-pub fn prove<F: PrimeField>(poly: &MultilinearPoly<F>, claimed_sum: F) -> Proof<F> {
-    let mut transcript = Transcript::new(Keccak256::new());
+pub fn prove<F: PrimeField, P: MultilinearExtension<F>>(poly: &P, claimed_sum: F) -> Proof<F> {
+    prove_in_extension(poly, claimed_sum)
+}
+
+/// Like `prove`, but draws the challenges from an extension `E` of the polynomial's field, as
+/// needed for soundness over small fields. Once the first variable is bound to a challenge the
+/// round sums live in `E`, so the whole proof does. With `E = F` this is exactly `prove`.
+pub fn prove_in_extension<F, E, P>(poly: &P, claimed_sum: F) -> Proof<E>
+where
+    F: PrimeField,
+    E: Field<BasePrimeField = F>,
+    P: MultilinearExtension<F>,
+{
+    let mut transcript = Transcript::<Keccak256, E>::new(Keccak256::new());
+    let claimed_sum = E::from_base_prime_field(claimed_sum);
 
     // Absorb the public inputs: the polynomial’s evaluation table and the claimed sum.
-    let poly_bytes = absorb_bytes(poly.evaluations.iter().copied());
+    let poly_bytes = absorb_bytes(poly.evaluations().iter().copied());
     transcript.absorb(&poly_bytes);
-    transcript.absorb(&claimed_sum.into_bigint().to_bytes_be());
+    transcript.absorb(&absorb_bytes([claimed_sum]));
 
-    let mut current_poly = poly.clone();
+    let mut current_poly = to_extension::<F, E, P>(poly);
     let mut rounds = Vec::with_capacity(current_poly.num_vars);
 
     // For each variable in the polynomial, produce a round polynomial.
     for _ in 0..current_poly.num_vars {
         // Compute the sum of evaluations after partially evaluating at 0 and 1.
-        let sum0: F = current_poly
-            .partial_evaluate((current_poly.num_vars - 1, F::zero()))
+        let sum0: E = current_poly
+            .partial_evaluate((current_poly.num_vars - 1, E::zero()))
             .evaluations
             .iter()
             .copied()
             .sum();
-        let sum1: F = current_poly
-            .partial_evaluate((current_poly.num_vars - 1, F::one()))
+        let sum1: E = current_poly
+            .partial_evaluate((current_poly.num_vars - 1, E::one()))
             .evaluations
             .iter()
             .copied()
@@ -76,34 +98,51 @@ pub fn prove<F: PrimeField>(poly: &MultilinearPoly<F>, claimed_sum: F) -> Proof<
 
 /// Runs a variant of the sum-check prover (e.g. for a GKR protocol) where each round polynomial
 /// is given at three points: 0, 1, and 2.
-pub fn partial_prove<F: PrimeField>(
-    poly: &MultilinearPoly<F>,
+pub fn partial_prove<F: PrimeField, P: MultilinearExtension<F>>(
+    poly: &P,
     claimed_sum: F,
     transcript: &mut Transcript<Keccak256, F>,
 ) -> PartialProof<F> {
+    partial_prove_in_extension(poly, claimed_sum, transcript)
+}
+
+/// Like `partial_prove`, but draws the challenges from an extension `E` of the polynomial's
+/// field. With `E = F` this is exactly `partial_prove`.
+pub fn partial_prove_in_extension<F, E, P>(
+    poly: &P,
+    claimed_sum: F,
+    transcript: &mut Transcript<Keccak256, E>,
+) -> PartialProof<E>
+where
+    F: PrimeField,
+    E: Field<BasePrimeField = F>,
+    P: MultilinearExtension<F>,
+{
+    let claimed_sum = E::from_base_prime_field(claimed_sum);
+
     // Absorb the public inputs.
-    let poly_bytes = absorb_bytes(poly.evaluations.iter().copied());
+    let poly_bytes = absorb_bytes(poly.evaluations().iter().copied());
     transcript.absorb(&poly_bytes);
-    transcript.absorb(&claimed_sum.into_bigint().to_bytes_be());
+    transcript.absorb(&absorb_bytes([claimed_sum]));
 
-    let mut current_poly = poly.clone();
+    let mut current_poly = to_extension::<F, E, P>(poly);
     let mut rounds = Vec::with_capacity(current_poly.num_vars);
 
     for _ in 0..current_poly.num_vars {
-        let sum0: F = current_poly
-            .partial_evaluate((current_poly.num_vars - 1, F::zero()))
+        let sum0: E = current_poly
+            .partial_evaluate((current_poly.num_vars - 1, E::zero()))
             .evaluations
             .iter()
             .copied()
             .sum();
-        let sum1: F = current_poly
-            .partial_evaluate((current_poly.num_vars - 1, F::one()))
+        let sum1: E = current_poly
+            .partial_evaluate((current_poly.num_vars - 1, E::one()))
             .evaluations
             .iter()
             .copied()
             .sum();
-        let sum2: F = current_poly
-            .partial_evaluate((current_poly.num_vars - 1, F::from(2)))
+        let sum2: E = current_poly
+            .partial_evaluate((current_poly.num_vars - 1, E::from(2u64)))
             .evaluations
             .iter()
             .copied()
@@ -124,20 +163,31 @@ pub fn partial_prove<F: PrimeField>(
 /// Verifies a sum-check proof. It returns `true` if the proof is valid.
 /// The verifier recomputes challenges and uses a final check that the final value equals the
 /// evaluation of the polynomial at those challenges.
-pub fn verify<F: PrimeField>(proof: &Proof<F>, poly: &mut MultilinearPoly<F>) -> bool {
-    if proof.round_polys.len() != poly.num_vars {
+pub fn verify<F: PrimeField, P: MultilinearExtension<F>>(proof: &Proof<F>, poly: &P) -> bool {
+    verify_in_extension(proof, poly)
+}
+
+/// Verifies a proof from `prove_in_extension`, evaluating the polynomial at the extension-field
+/// challenges in the final check.
+pub fn verify_in_extension<F, E, P>(proof: &Proof<E>, poly: &P) -> bool
+where
+    F: PrimeField,
+    E: Field<BasePrimeField = F>,
+    P: MultilinearExtension<F>,
+{
+    if proof.round_polys.len() != poly.num_vars() {
         return false;
     }
 
-    let mut transcript = Transcript::new(Keccak256::new());
-    transcript.absorb(&absorb_bytes(poly.evaluations.iter().copied()));
-    transcript.absorb(&proof.claimed_sum.into_bigint().to_bytes_be());
+    let mut transcript = Transcript::<Keccak256, E>::new(Keccak256::new());
+    transcript.absorb(&absorb_bytes(poly.evaluations().iter().copied()));
+    transcript.absorb(&absorb_bytes([proof.claimed_sum]));
 
     let mut computed_sum = proof.claimed_sum;
     let mut challenges = Vec::with_capacity(proof.round_polys.len());
 
     for round_poly in &proof.round_polys {
-        let round_total: F = round_poly.iter().copied().sum();
+        let round_total: E = round_poly.iter().copied().sum();
         if computed_sum != round_total {
             return false;
         }
@@ -150,48 +200,78 @@ pub fn verify<F: PrimeField>(proof: &Proof<F>, poly: &mut MultilinearPoly<F>) ->
         computed_sum = round_poly[0] + challenge * (round_poly[1] - round_poly[0]);
     }
 
+    // The prover binds the last variable first, so the challenges arrive in reverse order.
+    challenges.reverse();
+
     // Final check: the polynomial evaluated at the challenge points must equal computed_sum.
-    poly.evaluate(challenges) == computed_sum
+    to_extension::<F, E, P>(poly).evaluate(challenges) == computed_sum
 }
 
 /// Verifies a partial sum-check proof. Instead of returning a boolean, it returns the list of
 /// challenges and the final computed sum. (The caller can then compare the final computed sum
 /// to poly.evaluate(challenges).)
-pub fn partial_verify<F: PrimeField>(
+pub fn partial_verify<F: PrimeField, P: MultilinearExtension<F>>(
     proof: &PartialProof<F>,
-    poly: &mut MultilinearPoly<F>,
+    poly: &P,
     transcript: &mut Transcript<Keccak256, F>,
 ) -> (Vec<F>, F) {
-    if proof.round_polys.len() != poly.num_vars {
-        return (vec![], F::zero());
+    partial_verify_in_extension(proof, poly, transcript)
+}
+
+/// Verifies a proof from `partial_prove_in_extension`; the challenges and final sum it returns
+/// live in `E`.
+pub fn partial_verify_in_extension<F, E, P>(
+    proof: &PartialProof<E>,
+    poly: &P,
+    transcript: &mut Transcript<Keccak256, E>,
+) -> (Vec<E>, E)
+where
+    F: PrimeField,
+    E: Field<BasePrimeField = F>,
+    P: MultilinearExtension<F>,
+{
+    if proof.round_polys.len() != poly.num_vars() {
+        return (vec![], E::zero());
     }
 
-    transcript.absorb(&absorb_bytes(poly.evaluations.iter().copied()));
-    transcript.absorb(&proof.claimed_sum.into_bigint().to_bytes_be());
+    transcript.absorb(&absorb_bytes(poly.evaluations().iter().copied()));
+    transcript.absorb(&absorb_bytes([proof.claimed_sum]));
 
     let mut computed_sum = proof.claimed_sum;
     let mut challenges = Vec::with_capacity(proof.round_polys.len());
+    let weights = BarycentricWeights::consecutive(2);
 
     for round_poly in &proof.round_polys {
-        let round_total: F = round_poly.iter().copied().sum();
+        // Only the evaluations at 0 and 1 lie on the hypercube; the one at 2 is not summed.
+        let round_total = round_poly[0] + round_poly[1];
         if computed_sum != round_total {
-            return (vec![], F::zero());
+            return (vec![], E::zero());
         }
 
         transcript.absorb(&absorb_bytes(round_poly.iter().copied()));
         let challenge = transcript.squeeze();
         challenges.push(challenge);
 
-        computed_sum = round_poly[0] + challenge * (round_poly[1] - round_poly[0]);
+        // Interpolate the round polynomial through 0, 1 and 2 and evaluate it at the challenge.
+        computed_sum = evaluate_from_points(&weights, round_poly, challenge);
     }
 
+    challenges.reverse();
     (challenges, computed_sum)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_bn254::Fr;
+    use ark_bn254::{Fq, Fq2, Fr};
+    use ark_ff::Zero;
+    use polynomials::fields::baby_bear::{BabyBear, BabyBearExt4};
+    use polynomials::fields::goldilocks::{Goldilocks, GoldilocksExt2};
+    use polynomials::fields::mersenne31::{Mersenne31, Mersenne31Ext4};
+
+    fn to_field<F: PrimeField>(input: Vec<u64>) -> Vec<F> {
+        input.iter().map(|v| F::from(*v)).collect()
+    }
 
     /// Tests the standard sum-check protocol on a small multilinear polynomial.
     #[test]
@@ -200,8 +280,7 @@ mod tests {
         let poly = MultilinearPoly::new(3, to_field(vec![0, 0, 0, 3, 0, 0, 2, 5]));
         let claimed_sum = Fr::from(10);
         let proof = prove(&poly, claimed_sum);
-        let mut poly_for_verification = poly.clone();
-        assert!(verify(&proof, &mut poly_for_verification));
+        assert!(verify(&proof, &poly));
     }
 
     /// Tampering with a round polynomial should make verification fail.
@@ -214,8 +293,7 @@ mod tests {
         if let Some(first_round) = proof.round_polys.get_mut(0) {
             first_round[0] = Fr::from(999);
         }
-        let mut poly_for_verification = poly.clone();
-        assert!(!verify(&proof, &mut poly_for_verification));
+        assert!(!verify(&proof, &poly));
     }
 
     /// Test the partial sum-check protocol (with three evaluation points per round)
@@ -229,8 +307,7 @@ mod tests {
 
         // For verification, we create a fresh transcript.
         let mut verify_transcript = Transcript::new(Keccak256::new());
-        let mut poly_for_verification = poly.clone();
-        let (challenges, final_sum) = partial_verify(&partial_proof, &mut poly_for_verification, &mut verify_transcript);
+        let (challenges, final_sum) = partial_verify(&partial_proof, &poly, &mut verify_transcript);
 
         assert_eq!(poly.evaluate(challenges), final_sum);
     }
@@ -249,11 +326,67 @@ mod tests {
             round_poly[1] = Fr::from(999);
         }
         let mut verify_transcript = Transcript::new(Keccak256::new());
-        let mut poly_for_verification = poly.clone();
         let (challenges, final_sum) =
-            partial_verify(&partial_proof, &mut poly_for_verification, &mut verify_transcript);
+            partial_verify(&partial_proof, &poly, &mut verify_transcript);
 
         // Since the proof is invalid, we expect an empty challenge list or a zero final sum.
         assert!(challenges.is_empty() || final_sum.is_zero());
     }
+
+    /// Challenges drawn from Fq2 over a polynomial with Fq evaluations.
+    #[test]
+    fn test_sumcheck_with_extension_challenges() {
+        let poly = MultilinearPoly::new(3, to_field::<Fq>(vec![0, 0, 0, 3, 0, 0, 2, 5]));
+        let proof: Proof<Fq2> = prove_in_extension(&poly, Fq::from(10));
+        assert!(verify_in_extension(&proof, &poly));
+
+        // After the first round the sums depend on challenges outside the base field.
+        assert!(proof.round_polys[1..]
+            .iter()
+            .any(|round_poly| !round_poly[0].c1.is_zero()));
+
+        let mut tampered = proof.clone();
+        tampered.claimed_sum += Fq2::from(1);
+        tampered.round_polys[0][0] += Fq2::from(1);
+        assert!(!verify_in_extension(&tampered, &poly));
+    }
+
+    /// The three-point variant with challenges drawn from Fq2.
+    #[test]
+    fn test_partial_sumcheck_with_extension_challenges() {
+        let poly = MultilinearPoly::new(3, to_field::<Fq>(vec![0, 0, 0, 3, 0, 0, 2, 5]));
+        let mut transcript = Transcript::<Keccak256, Fq2>::new(Keccak256::new());
+        let proof = partial_prove_in_extension(&poly, Fq::from(10), &mut transcript);
+
+        let mut verify_transcript = Transcript::new(Keccak256::new());
+        let (challenges, final_sum) =
+            partial_verify_in_extension(&proof, &poly, &mut verify_transcript);
+        assert!(challenges.iter().any(|challenge| !challenge.c1.is_zero()));
+        assert_eq!(poly.to_extension::<Fq2>().evaluate(challenges), final_sum);
+    }
+
+    fn check_small_field<F, E>()
+    where
+        F: PrimeField,
+        E: Field<BasePrimeField = F>,
+    {
+        let poly = MultilinearPoly::new(3, to_field::<F>(vec![0, 0, 0, 3, 0, 0, 2, 5]));
+        let proof = prove(&poly, F::from(10u64));
+        assert!(verify(&proof, &poly));
+        assert!(!verify(&prove(&poly, F::from(11u64)), &poly));
+
+        let proof: Proof<E> = prove_in_extension(&poly, F::from(10u64));
+        assert!(verify_in_extension(&proof, &poly));
+        let mut tampered = proof.clone();
+        tampered.round_polys[2][1] += E::one();
+        assert!(!verify_in_extension(&tampered, &poly));
+    }
+
+    /// Sum-check over the 64- and 32-bit fields, with challenges from their extensions.
+    #[test]
+    fn test_sumcheck_over_small_fields() {
+        check_small_field::<Goldilocks, GoldilocksExt2>();
+        check_small_field::<BabyBear, BabyBearExt4>();
+        check_small_field::<Mersenne31, Mersenne31Ext4>();
+    }
 }